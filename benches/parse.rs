@@ -0,0 +1,88 @@
+//! Parse cost of detection, extraction, framing and checksum verification over a representative sentence mix.
+//!
+//! Run with `cargo bench`, optionally with `--features simd` to compare the vector paths against the scalar ones.
+use criterion::{Criterion, criterion_group, criterion_main};
+use gga_extract::{Framer, SentenceScanner, extract_gga, is_gga, try_extract_gga, verify_checksum};
+use std::hint::black_box;
+
+/// A second of output from a multi-constellation receiver at 1 Hz, with binary RTCM between sentences.
+const MIX: &[u8] = b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+    $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+    \xd3\x00\x13\x3e\xd0\x00\x03\x8a\x0e\xde\xef\x34\xb4\xbd\x62\xac\x09\x41\x98\x6f\x33\x18\x82\xb2\
+    $GNGSA,A,3,05,07,13,14,15,17,19,30,,,,,1.6,0.9,1.3*2C\r\n\
+    $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n";
+
+/// Circular buffer filled with repeats of [`MIX`], and the `(sentence_begin, sentence_length)` of each sentence.
+fn mix_buffer() -> ([u8; 1024], Vec<(usize, usize)>) {
+    let mut buffer = [0; 1024];
+    for (i, b) in buffer.iter_mut().enumerate() {
+        *b = MIX[i % MIX.len()];
+    }
+    let sentences = Framer::new(&buffer, 0, 1).collect();
+    (buffer, sentences)
+}
+
+/// Buffer holding the GGA sentence with a fix at `begin`.
+fn gga_at(begin: usize) -> [u8; 1024] {
+    let mut buffer = [0; 1024];
+    for (i, &b) in MIX[..75].iter().enumerate() {
+        buffer[(begin + i) % 1024] = b;
+    }
+    buffer
+}
+
+fn bench_is_gga(c: &mut Criterion) {
+    let (buffer, sentences) = mix_buffer();
+    c.bench_function("is_gga/mix", |b| {
+        b.iter(|| sentences.iter().filter(|&&(begin, _)| is_gga(black_box(&buffer), begin)).count())
+    });
+}
+
+fn bench_extract_gga(c: &mut Criterion) {
+    let mut position_block = [0; 10];
+    // Contiguous field reads, and a sentence straddling the wrap point through the latitude
+    for (name, begin) in [("extract_gga/contiguous", 100), ("extract_gga/wrapped", 1000)] {
+        let buffer = gga_at(begin);
+        c.bench_function(name, |b| {
+            b.iter(|| extract_gga(black_box(&buffer), black_box(begin), &mut position_block))
+        });
+    }
+    let buffer = gga_at(100);
+    c.bench_function("try_extract_gga/contiguous", |b| {
+        b.iter(|| try_extract_gga(black_box(&buffer), black_box(100)))
+    });
+}
+
+fn bench_scanner(c: &mut Criterion) {
+    let (buffer, _) = mix_buffer();
+    c.bench_function("framer/full_buffer", |b| {
+        b.iter(|| Framer::new(black_box(&buffer), 0, 1).count())
+    });
+    c.bench_function("scanner/on_idle", |b| {
+        b.iter(|| {
+            let mut scanner = SentenceScanner::new();
+            scanner.on_idle(black_box(&buffer), 1).count()
+        })
+    });
+}
+
+fn bench_verify_checksum(c: &mut Criterion) {
+    let (buffer, sentences) = mix_buffer();
+    c.bench_function("verify_checksum/mix", |b| {
+        b.iter(|| {
+            sentences
+                .iter()
+                .filter(|&&(begin, length)| verify_checksum(black_box(&buffer), begin, length))
+                .count()
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_is_gga,
+    bench_extract_gga,
+    bench_scanner,
+    bench_verify_checksum
+);
+criterion_main!(benches);
@@ -0,0 +1,139 @@
+//! GGA fixes from serial drivers implementing `embedded_io_async::Read`, for async executors such as Embassy.
+use crate::fix::GgaFix;
+use crate::stream::GgaStream;
+
+/// Number of bytes requested from the reader at a time.
+const CHUNK_LENGTH: usize = 32;
+
+/// Awaits bytes from an `embedded_io_async::Read` implementor and yields the fixes of GGA sentences.
+///
+/// Bytes are read in chunks and fed through [`GgaStream`], so sentences may span any number of reads. Bytes read past
+/// the end of a sentence are kept for the next call.
+#[derive(Debug)]
+pub struct FixStream<R> {
+    reader: R,
+    stream: GgaStream,
+    chunk: [u8; CHUNK_LENGTH],
+    index: usize,
+    length: usize,
+}
+
+impl<R: embedded_io_async::Read> FixStream<R> {
+    /// Create a stream waiting for the start of a sentence.
+    #[inline]
+    pub const fn new(reader: R) -> FixStream<R> {
+        FixStream {
+            reader,
+            stream: GgaStream::new(),
+            chunk: [0; CHUNK_LENGTH],
+            index: 0,
+            length: 0,
+        }
+    }
+
+    /// Await bytes until a GGA sentence containing a fix is complete.
+    ///
+    /// ### Returns
+    /// The fix, `None` if the reader reached the end of its input, or the reader's error.
+    pub async fn next_fix(&mut self) -> Result<Option<GgaFix>, R::Error> {
+        loop {
+            while self.index < self.length {
+                let byte = self.chunk[self.index];
+                self.index += 1;
+                if let Some(fix) = self.stream.push(byte) {
+                    return Ok(Some(fix));
+                }
+            }
+            self.index = 0;
+            self.length = self.reader.read(&mut self.chunk).await?;
+            if self.length == 0 {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Give back the reader. Bytes already read but not yet parsed are lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_gga_struct;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    /// Serial driver returning at most `limit` bytes per read, and pending before every read.
+    struct Uart<'a> {
+        bytes: &'a [u8],
+        limit: usize,
+        ready: bool,
+    }
+
+    impl embedded_io_async::ErrorType for Uart<'_> {
+        type Error = embedded_io_async::ErrorKind;
+    }
+
+    impl embedded_io_async::Read for Uart<'_> {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            core::future::poll_fn(|context| {
+                self.ready = !self.ready;
+                if self.ready {
+                    Poll::Ready(())
+                } else {
+                    context.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+            .await;
+            let length = self.limit.min(buf.len()).min(self.bytes.len());
+            buf[..length].copy_from_slice(&self.bytes[..length]);
+            self.bytes = &self.bytes[length..];
+            Ok(length)
+        }
+    }
+
+    /// Poll a future to completion on the current thread.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_fix_stream() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_WITH_FIX, 0);
+        let expected_fix = extract_gga_struct(&buffer, 0).unwrap();
+
+        for limit in 1..=STREAM.len() {
+            let mut stream = FixStream::new(Uart {
+                bytes: STREAM,
+                limit,
+                ready: false,
+            });
+            block_on(async {
+                assert_eq!(stream.next_fix().await, Ok(Some(expected_fix)));
+                assert_eq!(stream.next_fix().await, Ok(Some(expected_fix)));
+                assert_eq!(stream.next_fix().await, Ok(None));
+            });
+            assert!(stream.into_inner().bytes.is_empty());
+        }
+    }
+
+    const STREAM: &[u8] = b"73,M,41.1,M,,*59\r\n\
+        $GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGGA,0512$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNGGA,0512";
+}
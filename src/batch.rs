@@ -0,0 +1,218 @@
+//! Extract every GGA fix in a received window at once.
+use crate::buffer::NmeaBuffer;
+use crate::fix::{GgaFix, extract_gga_struct};
+use crate::frame::Framer;
+use crate::sentence::{SentenceType, classify};
+use crate::{extract_gga, is_gga};
+
+/// Outcome of [`extract_window`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WindowSummary {
+    /// Number of complete sentences of any type in the window.
+    pub sentences: usize,
+    /// Number of position blocks stored.
+    pub fixes: usize,
+    /// Index where the next window should begin, see [`Framer::resume_index`].
+    pub resume_index: usize,
+}
+
+/// Extract the fixes of every GGA sentence in a window of a circular buffer.
+///
+/// If the window contains more fixes than `out` can hold, the newest are kept. A single element `out` therefore
+/// receives the latest fix.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `window_begin` - Index of the first received byte to scan.
+/// * `window_len` - Number of received bytes to scan.
+/// * `out` - Output slice where fixes will be stored, oldest first.
+///
+/// ### Returns
+/// The number of fixes stored in `out`. GGA sentences without a fix and incomplete sentences are skipped.
+#[inline]
+pub fn extract_all_gga<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    window_begin: usize,
+    window_len: usize,
+    out: &mut [GgaFix],
+) -> usize {
+    if out.is_empty() || buffer.is_empty() {
+        return 0;
+    }
+
+    let mut count = 0;
+    for (sentence_begin, _) in Framer::with_length(buffer, window_begin, window_len) {
+        if !is_gga(buffer, sentence_begin) {
+            continue;
+        }
+        if let Some(fix) = extract_gga_struct(buffer, sentence_begin) {
+            // Overwrite the oldest fix once full
+            out[count % out.len()] = fix;
+            count += 1;
+        }
+    }
+
+    if count > out.len() {
+        out.rotate_left(count % out.len());
+        out.len()
+    } else {
+        count
+    }
+}
+
+/// Frame, classify and extract every GGA sentence received since the previous window in one pass.
+///
+/// Each sentence is located and classified once, and GGA sentences are parsed straight from the index the framer
+/// found, instead of calling [`is_gga`] and [`extract_gga`] separately per sentence. If the window contains more
+/// fixes than `position_blocks` can hold, the newest are kept.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `begin` - Index to start scanning from, usually the resume index of the previous window.
+/// * `ndtr` - Current value of the DMA NDTR register, counting down from the buffer length.
+/// * `position_blocks` - Output position blocks as written by [`extract_gga`], oldest first.
+///
+/// ### Returns
+/// How many sentences and fixes were found, and where to resume.
+#[inline]
+pub fn extract_window<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    begin: usize,
+    ndtr: u16,
+    position_blocks: &mut [[u8; 10]],
+) -> WindowSummary {
+    let mut framer = Framer::new(buffer, begin, ndtr);
+    let mut summary = WindowSummary::default();
+    let mut count = 0;
+    for (sentence_begin, _) in framer.by_ref() {
+        summary.sentences += 1;
+        if position_blocks.is_empty() || classify(buffer, sentence_begin) != SentenceType::Gga {
+            continue;
+        }
+        // Overwrite the oldest position block once full
+        if extract_gga(
+            buffer,
+            sentence_begin,
+            &mut position_blocks[count % position_blocks.len()],
+        ) {
+            count += 1;
+        }
+    }
+    summary.resume_index = framer.resume_index();
+
+    summary.fixes = if count > position_blocks.len() {
+        position_blocks.rotate_left(count % position_blocks.len());
+        position_blocks.len()
+    } else {
+        count
+    };
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    fn fix_of(sentence: &[u8]) -> GgaFix {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, sentence, 0);
+        extract_gga_struct(&buffer, 0).unwrap()
+    }
+
+    #[test]
+    fn test_extract_all_gga() {
+        let expected_fixes = [fix_of(GGA[0]), fix_of(GGA[1]), fix_of(GGA[2])];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, WINDOW, i);
+            let mut out = [GgaFix::default(); 4];
+            assert_eq!(extract_all_gga(&buffer, i, WINDOW.len(), &mut out), 3);
+            assert_eq!(out[..3], expected_fixes);
+        }
+    }
+
+    #[test]
+    fn test_extract_all_gga_keeps_newest() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, WINDOW, i);
+            let mut out = [GgaFix::default(); 2];
+            assert_eq!(extract_all_gga(&buffer, i, WINDOW.len(), &mut out), 2);
+            assert_eq!(out, [fix_of(GGA[1]), fix_of(GGA[2])]);
+            let mut out = [GgaFix::default(); 1];
+            assert_eq!(extract_all_gga(&buffer, i, WINDOW.len(), &mut out), 1);
+            assert_eq!(out, [fix_of(GGA[2])]);
+            assert_eq!(extract_all_gga(&buffer, i, WINDOW.len(), &mut []), 0);
+        }
+    }
+
+    #[test]
+    fn test_extract_all_gga_empty_buffer() {
+        let mut out = [GgaFix::default(); 2];
+        assert_eq!(extract_all_gga(&[][..], 0, WINDOW.len(), &mut out), 0);
+        assert_eq!(extract_all_gga(&[][..], 5, 0, &mut out), 0);
+        assert_eq!(out, [GgaFix::default(); 2]);
+    }
+
+    #[test]
+    fn test_extract_window() {
+        let mut expected_blocks = [[0; 10]; 3];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, block) in GGA.iter().zip(expected_blocks.iter_mut()) {
+            shift_buffer(&mut buffer, sentence, 0);
+            assert!(extract_gga(&buffer, 0, block));
+        }
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, WINDOW, i);
+            let ndtr = (1024 - ((i + WINDOW.len()) & 1023)) as u16;
+            let resume_index = (i + WINDOW.len() - 23) & 1023;
+
+            let mut position_blocks = [[0; 10]; 4];
+            let summary = extract_window(&buffer, i, ndtr, &mut position_blocks);
+            assert_eq!(
+                summary,
+                WindowSummary {
+                    sentences: 5,
+                    fixes: 3,
+                    resume_index,
+                }
+            );
+            assert_eq!(position_blocks[..3], expected_blocks);
+
+            let mut position_blocks = [[0; 10]; 2];
+            assert_eq!(extract_window(&buffer, i, ndtr, &mut position_blocks).fixes, 2);
+            assert_eq!(position_blocks, expected_blocks[1..]);
+
+            let summary = extract_window(&buffer, i, ndtr, &mut []);
+            assert_eq!((summary.sentences, summary.fixes), (5, 0));
+        }
+    }
+
+    #[test]
+    fn test_extract_window_out_of_range() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, WINDOW, 0);
+        let mut position_blocks = [[0; 10]; 4];
+        let summary = extract_window(&buffer, 100, 1025, &mut position_blocks);
+        assert_eq!((summary.sentences, summary.fixes), (0, 0));
+        assert_eq!(summary.resume_index, 100);
+
+        let summary = extract_window(&[][..], 10, 0, &mut position_blocks);
+        assert_eq!(summary, WindowSummary::default());
+    }
+
+    const GGA: [&[u8]; 3] = [
+        b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+        b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,1,03,2.10,84.6,M,41.1,M,,*6E\r\n",
+        b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,1,03,39.9,84.6,M,41.1,M,,*6E\r\n",
+    ];
+
+    const WINDOW: &[u8] = b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GPGGA,181501.000,3944.50086,N,10459.16654,W,1,03,2.10,84.6,M,41.1,M,,*6E\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGGA,181501.000,3615.12012,S,06357.25158,W,1,03,39.9,84.6,M,41.1,M,,*6E\r\n\
+        $GNGGA,181501.000,3615.";
+}
@@ -0,0 +1,142 @@
+//! Storage abstraction the sentence extractors read from.
+
+/// A circular buffer containing NMEA 0183 data.
+///
+/// Extractors read at `sentence_begin + offset` without checking for the end of the buffer, so `at` must wrap any
+/// index into the buffer. The parsing path is only free of panics, as checked with the `no-panic` feature, if `at`
+/// cannot panic either.
+pub trait NmeaBuffer {
+    /// Read the byte at `index`, wrapped to the length of the buffer.
+    fn at(&self, index: usize) -> u8;
+
+    /// Length of the buffer in bytes.
+    fn len(&self) -> usize;
+
+    /// If the buffer has no storage.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The whole buffer as one slice, if it is stored contiguously, so it can be scanned several bytes at a time.
+    #[inline]
+    fn contiguous(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+impl<const N: usize> NmeaBuffer for [u8; N] {
+    #[inline(always)]
+    fn at(&self, index: usize) -> u8 {
+        // An empty array would divide by zero, so it fails to compile instead of checking at run time
+        const { assert!(N > 0, "an empty array cannot be a circular buffer") };
+        // The modulo keeps the index in bounds, so the check is optimised out. It is a mask for power-of-two sizes.
+        self[index % N]
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn contiguous(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+impl NmeaBuffer for [u8] {
+    #[inline(always)]
+    fn at(&self, index: usize) -> u8 {
+        // An empty slice reads as zeros instead of dividing by zero
+        index.checked_rem(self.len()).map_or(0, |index| self[index])
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn contiguous(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+    use crate::{extract_gga, extract_rmc, is_gga};
+
+    /// Two regions of a DMA descriptor chain read as one circular buffer.
+    struct SplitBuffer<'a> {
+        first: &'a [u8],
+        second: &'a [u8],
+    }
+
+    impl NmeaBuffer for SplitBuffer<'_> {
+        fn at(&self, index: usize) -> u8 {
+            // Reads without panicking, so the lib tests still link with the no-panic feature
+            let Some(index) = index.checked_rem(self.len()) else {
+                return 0;
+            };
+            match index.checked_sub(self.first.len()) {
+                None => self.first.get(index),
+                Some(index) => self.second.get(index),
+            }
+            .map_or(0, |&b| b)
+        }
+
+        fn len(&self) -> usize {
+            self.first.len() + self.second.len()
+        }
+    }
+
+    #[test]
+    fn test_array_wraps() {
+        let buffer: [u8; 4] = [1, 2, 3, 4];
+        assert_eq!(buffer.at(1), 2);
+        assert_eq!(buffer.at(5), 2);
+        assert_eq!(NmeaBuffer::len(&buffer), 4);
+        assert!(!NmeaBuffer::is_empty(&buffer));
+    }
+
+    #[test]
+    fn test_split_buffer() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut expected_position_block = [0; 10];
+        let mut position_block = [0; 10];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            assert!(extract_gga(&buffer, i, &mut expected_position_block));
+            let (first, second) = buffer.split_at(384);
+            let split = SplitBuffer { first, second };
+            assert!(is_gga(&split, i));
+            assert!(extract_gga(&split, i, &mut position_block));
+            assert_eq!(position_block, expected_position_block);
+        }
+    }
+
+    #[test]
+    fn test_split_buffer_odd_length() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut expected_motion_block = [0; 13];
+        shift_buffer(&mut buffer, RMC, 0);
+        assert!(extract_rmc(&buffer, 0, &mut expected_motion_block));
+
+        let mut storage: [u8; 300] = [0; 300];
+        let mut motion_block = [0; 13];
+        for i in 0..300 {
+            for (j, b) in RMC.iter().enumerate() {
+                storage[(i + j) % 300] = *b;
+            }
+            let (first, second) = storage.split_at(100);
+            let split = SplitBuffer { first, second };
+            assert!(extract_rmc(&split, i, &mut motion_block));
+            assert_eq!(motion_block, expected_motion_block);
+        }
+    }
+
+    const RMC: &[u8] = b"$GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n";
+}
@@ -0,0 +1,256 @@
+//! Bounds-checked, validating GGA extraction.
+use crate::buffer::NmeaBuffer;
+use crate::degrees::total_minutes;
+use crate::fix::GgaFix;
+
+/// Number of bytes from `$` read by the fixed-offset GGA extractors, up to the HDOP tenths digit at offset 53.
+pub(crate) const GGA_FIXED_SPAN: usize = 54;
+
+/// Reasons a GGA sentence could not be extracted by [`try_extract_gga`].
+///
+/// Offsets are relative to the `$` at the start of the sentence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GgaError {
+    /// The time field is empty, assume no fix.
+    NoTime,
+    /// The latitude field is empty, no fix.
+    NoFix,
+    /// The byte at this offset should be an ASCII digit.
+    InvalidDigit(usize),
+    /// The byte at this offset should be a `,` or `.` delimiter.
+    InvalidDelimiter(usize),
+    /// The byte at this offset should be a hemisphere indicator.
+    InvalidHemisphere(usize),
+    /// The sentence has this many fields instead of 15.
+    InvalidFieldCount(usize),
+    /// The DMA may have overwritten the sentence while it was being parsed.
+    Torn,
+}
+
+/// Extract position data from a GGA sentence in a circular buffer, validating every byte that is read.
+///
+/// This follows the same fixed-offset layout as [`extract_gga`](crate::extract_gga) but uses bounds-checked reads and
+/// rejects malformed input instead of producing garbage.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+///
+/// ### Returns
+/// The fix, or why the sentence was rejected.
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+pub fn try_extract_gga<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> Result<GgaFix, GgaError> {
+    // Copy out the span so every read below is at a constant offset into an array, and needs no bounds check
+    let mut span = [0; GGA_FIXED_SPAN];
+    for (offset, b) in span.iter_mut().enumerate() {
+        *b = buffer.at(sentence_begin + offset);
+    }
+    parse_span(&span)
+}
+
+/// Validate and parse the fixed-offset fields of a GGA sentence copied out of the buffer.
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+#[inline(always)]
+fn parse_span(span: &[u8; GGA_FIXED_SPAN]) -> Result<GgaFix, GgaError> {
+    let at = |offset: usize| span[offset];
+    let digit = |offset: usize| {
+        let b = at(offset);
+        if b.is_ascii_digit() {
+            Ok((b - b'0') as u32)
+        } else {
+            Err(GgaError::InvalidDigit(offset))
+        }
+    };
+    let delimiter = |offset: usize, expected: u8| {
+        if at(offset) == expected {
+            Ok(())
+        } else {
+            Err(GgaError::InvalidDelimiter(offset))
+        }
+    };
+    let digits = |offsets: core::ops::Range<usize>| {
+        let mut value: u32 = 0;
+        for offset in offsets {
+            value = value * 10 + digit(offset)?;
+        }
+        Ok(value)
+    };
+
+    // Check time field
+    if at(7) == b',' {
+        return Err(GgaError::NoTime);
+    }
+    digits(7..13)?;
+    delimiter(13, b'.')?;
+    digits(14..17)?;
+    delimiter(17, b',')?;
+
+    // Check latitude field
+    if at(18) == b',' {
+        return Err(GgaError::NoFix);
+    }
+
+    // Parse latitude
+    let lat_degrees_minutes = digits(18..22)?;
+    delimiter(22, b'.')?;
+    let lat_fraction = digits(23..28)?;
+    delimiter(28, b',')?;
+    let north = match at(29) {
+        b'N' => true,
+        b'S' => false,
+        _ => return Err(GgaError::InvalidHemisphere(29)),
+    };
+    delimiter(30, b',')?;
+
+    // Parse longitude
+    let lon_degrees_minutes = digits(31..36)?;
+    delimiter(36, b'.')?;
+    let lon_fraction = digits(37..42)?;
+    delimiter(42, b',')?;
+    let east = match at(43) {
+        b'E' => true,
+        b'W' => false,
+        _ => return Err(GgaError::InvalidHemisphere(43)),
+    };
+    delimiter(44, b',')?;
+
+    let quality = digit(45)? as u8;
+    delimiter(46, b',')?;
+    digits(47..49)?;
+    delimiter(49, b',')?;
+
+    // Parse hdop
+    let hdop = if at(51) == b'.' {
+        // Integer part is single digit
+        digit(50)? * 10 + digit(52)?
+    } else {
+        // Integer part is double digit
+        let hdop = digits(50..52)? * 10;
+        delimiter(52, b'.')?;
+        hdop + digit(53)?
+    };
+
+    Ok(GgaFix {
+        // Wraps for latitudes of 42.95° and above and out of range longitude digits, as extract_gga does
+        lat: lat_degrees_minutes.wrapping_mul(100_000).wrapping_add(lat_fraction).wrapping_mul(10),
+        lat_total_minutes: total_minutes(lat_degrees_minutes.wrapping_mul(100_000).wrapping_add(lat_fraction)),
+        lon: lon_degrees_minutes.wrapping_mul(100_000).wrapping_add(lon_fraction),
+        flags: ((north as u8) << 1) | east as u8,
+        hdop: if hdop < 256 { hdop as u8 } else { 255 },
+        quality,
+    })
+}
+
+/// Extract position data from a GGA sentence in a contiguous slice, for sentences reassembled outside a circular
+/// buffer.
+///
+/// ### Arguments
+/// * `sentence` - A single GGA sentence starting with `$`.
+///
+/// ### Returns
+/// The fix if the sentence contains one and is well formed. Reads never go past the end of the slice.
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+pub fn extract_gga_slice(sentence: &[u8]) -> Option<GgaFix> {
+    // Only the fixed span is read, so parse it in place without wrapping each index
+    let span = sentence.first_chunk::<GGA_FIXED_SPAN>()?;
+    parse_span(span).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_gga_struct;
+    use crate::tests::{GGA_HIGH_LATITUDE, shift_buffer};
+
+    #[test]
+    fn test_try_extract_gga_valid() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in GGA_VALID.iter().chain([&GGA_HIGH_LATITUDE]) {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(try_extract_gga(&buffer, i), Ok(extract_gga_struct(&buffer, i).unwrap()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_extract_gga_invalid() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_error) in GGA_INVALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(try_extract_gga(&buffer, i), Err(*expected_error));
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_gga_slice() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in GGA_VALID.iter() {
+            shift_buffer(&mut buffer, sentence, 0);
+            assert_eq!(extract_gga_slice(sentence), extract_gga_struct(&buffer, 0));
+            // Without the line ending
+            assert_eq!(
+                extract_gga_slice(&sentence[..sentence.len() - 2]),
+                extract_gga_struct(&buffer, 0)
+            );
+            // Truncated before HDOP
+            assert_eq!(extract_gga_slice(&sentence[..50]), None);
+        }
+        for (sentence, _) in GGA_INVALID.iter() {
+            assert_eq!(extract_gga_slice(sentence), None);
+        }
+        assert_eq!(extract_gga_slice(b""), None);
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn test_strict_extract_gga() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, _) in GGA_INVALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(!crate::extract_gga(&buffer, i, &mut position_block));
+                assert_eq!(position_block, [0; 10]);
+            }
+        }
+    }
+
+    const GGA_VALID: [&[u8]; 4] = [
+        b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+        b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,1,03,2.10,84.6,M,41.1,M,,*6E\r\n",
+        b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,1,03,39.9,84.6,M,41.1,M,,*6E\r\n",
+        // Every digit at its maximum
+        b"$GNGGA,999999.999,9999.99999,N,99999.99999,E,1,99,99.9,84.6,M,41.1,M,,*6E\r\n",
+    ];
+
+    const GGA_INVALID: [(&[u8], GgaError); 7] = [
+        (b"$GNGGA,,,,,,0,00,25.5,,,,,,*64\r\n", GgaError::NoTime),
+        (b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n", GgaError::NoFix),
+        (
+            b"$GNGGA,051200.993,27#4.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            GgaError::InvalidDigit(20),
+        ),
+        // 4 decimal places
+        (
+            b"$GNGGA,051200.993,2734.2197,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            GgaError::InvalidDigit(27),
+        ),
+        (
+            b"$GNGGA,051200.99,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            GgaError::InvalidDigit(16),
+        ),
+        (
+            b"$GNGGA,051200.993,2734.21973,X,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            GgaError::InvalidHemisphere(29),
+        ),
+        (
+            b"$GNGGA,051200.993,2734,21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            GgaError::InvalidDelimiter(22),
+        ),
+    ];
+}
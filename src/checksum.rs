@@ -0,0 +1,226 @@
+//! NMEA 0183 checksum verification and computation.
+use crate::buffer::NmeaBuffer;
+use crate::trace::log_debug;
+
+/// Marks a byte that is not an ASCII hex digit in [`HEX_NIBBLES`]. Any value with high bits set would do.
+const NOT_HEX: u8 = 0xFF;
+
+/// Value of every byte as an ASCII hex digit, either case, or [`NOT_HEX`], so decoding does not branch per character.
+static HEX_NIBBLES: [u8; 256] = {
+    let mut nibbles = [NOT_HEX; 256];
+    let mut i = 0;
+    while i < 10 {
+        nibbles[b'0' as usize + i] = i as u8;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 6 {
+        nibbles[b'A' as usize + i] = 10 + i as u8;
+        nibbles[b'a' as usize + i] = 10 + i as u8;
+        i += 1;
+    }
+    nibbles
+};
+
+/// Value of an ASCII hex digit, either case, or `None` if the byte is not one.
+#[inline(always)]
+pub(crate) fn hex_value(b: u8) -> Option<u8> {
+    let nibble = HEX_NIBBLES[b as usize];
+    if nibble == NOT_HEX { None } else { Some(nibble) }
+}
+
+/// Uppercase ASCII hex digits.
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Compute the checksum of an outgoing sentence as two uppercase hex digits.
+///
+/// ### Arguments
+/// * `payload` - The bytes between `$` and `*`, e.g. `b"PMTK220,100"`. A leading `$` is skipped.
+///
+/// ### Returns
+/// The digits to write after the `*`.
+#[inline]
+pub const fn checksum(payload: &[u8]) -> [u8; 2] {
+    let mut checksum: u8 = 0;
+    let mut i = if !payload.is_empty() && payload[0] == b'$' {
+        1
+    } else {
+        0
+    };
+    while i < payload.len() {
+        checksum ^= payload[i];
+        i += 1;
+    }
+    [
+        HEX_DIGITS[(checksum >> 4) as usize],
+        HEX_DIGITS[(checksum & 0xF) as usize],
+    ]
+}
+
+/// Append the checksum and line ending to a sentence built by the caller, e.g. a receiver configuration command.
+///
+/// ### Arguments
+/// * `buf` - Holds the sentence body, e.g. `$PMTK220,100`, followed by at least 5 free bytes. A leading `$` is not
+///   included in the checksum.
+/// * `payload_len` - Length of the sentence body in `buf`.
+///
+/// ### Returns
+/// Length of the finished sentence, `payload_len + 5`, or 0 if `buf` has no room for `*hh\r\n`.
+#[inline]
+pub fn finalize_sentence(buf: &mut [u8], payload_len: usize) -> usize {
+    let Some(length) = payload_len.checked_add(5) else {
+        return 0;
+    };
+    let Some(sentence) = buf.get_mut(..length) else {
+        return 0;
+    };
+    let digits = checksum(&sentence[..payload_len]);
+    sentence[payload_len] = b'*';
+    sentence[payload_len + 1..payload_len + 3].copy_from_slice(&digits);
+    sentence[payload_len + 3..].copy_from_slice(b"\r\n");
+    length
+}
+
+/// Verify the checksum of a sentence in a circular buffer.
+///
+/// The checksum is the XOR of every byte between `$` and `*`, written as two hex digits after the `*`.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the sentence in the buffer.
+/// * `sentence_len` - Length of the sentence, as from [`calculate_sentence_length`](crate::calculate_sentence_length).
+///
+/// ### Returns
+/// If the sentence has a checksum and it matches. Sentences without a checksum are rejected.
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+pub fn verify_checksum<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, sentence_len: usize) -> bool {
+    let mut checksum: u8 = 0;
+    // Leave room for the two checksum digits
+    for i in 1..sentence_len.saturating_sub(2) {
+        let b = buffer.at(sentence_begin + i);
+        if b == b'*' {
+            let high = HEX_NIBBLES[buffer.at(sentence_begin + i + 1) as usize];
+            let low = HEX_NIBBLES[buffer.at(sentence_begin + i + 2) as usize];
+            // Both digits are hex if neither has its high bits set
+            let valid = ((high | low) & 0xF0 == 0) & ((high << 4 | low) == checksum);
+            if !valid {
+                log_debug!("checksum mismatch in sentence at {}", sentence_begin);
+            }
+            return valid;
+        }
+        checksum ^= b;
+    }
+    log_debug!("no checksum in sentence at {}", sentence_begin);
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_verify_checksum() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_valid) in CHECKSUMS.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(verify_checksum(&buffer, i, sentence.len()), *expected_valid);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_checksum_truncated() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let sentence = CHECKSUMS[0].0;
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, sentence, i);
+            // Cut inside the checksum
+            assert!(!verify_checksum(&buffer, i, sentence.len() - 3));
+            // Without the line ending
+            assert!(verify_checksum(&buffer, i, sentence.len() - 2));
+            assert!(!verify_checksum(&buffer, i, 0));
+        }
+    }
+
+    #[test]
+    fn test_finalize_sentence() {
+        let mut buf = [0; 32];
+        buf[..12].copy_from_slice(b"$PMTK220,100");
+        assert_eq!(finalize_sentence(&mut buf, 12), 17);
+        assert_eq!(&buf[..17], b"$PMTK220,100*2F\r\n");
+
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_valid) in CHECKSUMS.iter() {
+            if !*expected_valid {
+                continue;
+            }
+            let star = sentence.iter().position(|&b| b == b'*').unwrap();
+            let mut buf = [0; 82];
+            buf[..star].copy_from_slice(&sentence[..star]);
+            let length = finalize_sentence(&mut buf, star);
+            assert_eq!(length, star + 5);
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, &buf[..length], i);
+                assert!(verify_checksum(&buffer, i, length));
+            }
+        }
+
+        // No room for the checksum and line ending
+        let mut buf = *b"$PMTK220,100*2F\r";
+        assert_eq!(finalize_sentence(&mut buf, 12), 0);
+        assert_eq!(&buf, b"$PMTK220,100*2F\r");
+        assert_eq!(finalize_sentence(&mut [], 0), 0);
+        assert_eq!(finalize_sentence(&mut buf, usize::MAX - 4), 0);
+    }
+
+    #[test]
+    fn test_hex_value() {
+        for b in 0..=255u8 {
+            assert_eq!(hex_value(b), (b as char).to_digit(16).map(|digit| digit as u8));
+        }
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(b"PMTK220,100"), *b"2F");
+        assert_eq!(checksum(b"$PMTK220,100"), *b"2F");
+        assert_eq!(checksum(b"PUBX,40,GLL,0,0,0,0,0,0"), *b"5C");
+        assert_eq!(checksum(b""), *b"00");
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_valid) in CHECKSUMS.iter() {
+            if !*expected_valid {
+                continue;
+            }
+            let star = sentence.iter().position(|&b| b == b'*').unwrap();
+            let digits = checksum(&sentence[..star]);
+            assert!(digits.eq_ignore_ascii_case(&sentence[star + 1..star + 3]));
+            shift_buffer(&mut buffer, &sentence[..star + 1], 0);
+            shift_buffer(&mut buffer, &digits, star + 1);
+            assert!(verify_checksum(&buffer, 0, star + 3));
+        }
+    }
+
+    const CHECKSUMS: [(&[u8], bool); 8] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            true,
+        ),
+        (
+            b"$GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n",
+            true,
+        ),
+        (b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n", true),
+        (b"$HEHDT,5.1,T*2b\r\n", true),
+        // Single bit error in the latitude
+        (
+            b"$GNGGA,051200.993,2734.21972,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            false,
+        ),
+        (b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7F\r\n", false),
+        (b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7G\r\n", false),
+        (b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,\r\n", false),
+    ];
+}
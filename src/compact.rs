@@ -0,0 +1,150 @@
+//! Compact 6-byte position encoding for small uplink payloads.
+use crate::buffer::NmeaBuffer;
+use crate::degrees::total_minutes;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Largest magnitude of a 24-bit signed value, at 90° of latitude or 180° of longitude.
+const COMPACT_FULL_SCALE: u64 = 1 << 23;
+
+/// Quantise minutes scaled by 10^5 to a 24-bit fraction of `full_scale_minutes`, rounding to nearest.
+#[inline(always)]
+const fn quantise(minutes: u32, full_scale_minutes: u64) -> u32 {
+    let quantised = (minutes as u64 * COMPACT_FULL_SCALE + full_scale_minutes / 2) / full_scale_minutes;
+    if quantised < COMPACT_FULL_SCALE {
+        quantised as u32
+    } else {
+        COMPACT_FULL_SCALE as u32 - 1
+    }
+}
+
+/// Extract position data from a GGA sentence in a circular buffer as a compact 6-byte block.
+///
+/// Latitude resolution is about 1.2 m and longitude resolution about 2.4 m at the equator. The hemispheres are carried
+/// in the signs, and HDOP is not included. The position block layout is:
+/// * `[0..3]` - Latitude in units of 90° / 2^23, signed 24-bit big-endian. Negative if south.
+/// * `[3..6]` - Longitude in units of 180° / 2^23, signed 24-bit big-endian. Negative if west.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (6 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix.
+#[inline]
+pub fn extract_gga_compact<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 6],
+) -> bool {
+    // Check time field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        // No time field, assume no fix
+        return false;
+    }
+
+    // Check latitude field
+    if byte_at(buffer, sentence_begin + 18) == b',' {
+        // No latitude field, no fix
+        return false;
+    }
+
+    // 90° is 5.4 * 10^8 minutes scaled by 10^5
+    let (lat, end) = parse_decimal(buffer, sentence_begin + 18, 5);
+    let lat_hemisphere = skip_field(buffer, end);
+    let lat = quantise(total_minutes(lat), 540_000_000);
+    let lat = if byte_at(buffer, lat_hemisphere) == b'S' {
+        lat.wrapping_neg()
+    } else {
+        lat
+    };
+    position_block[0] = (lat >> 16) as u8;
+    position_block[1] = (lat >> 8) as u8;
+    position_block[2] = lat as u8;
+
+    let (lon, end) = parse_decimal(buffer, skip_field(buffer, lat_hemisphere), 5);
+    let lon_hemisphere = skip_field(buffer, end);
+    let lon = quantise(total_minutes(lon), 1_080_000_000);
+    let lon = if byte_at(buffer, lon_hemisphere) == b'W' {
+        lon.wrapping_neg()
+    } else {
+        lon
+    };
+    position_block[3] = (lon >> 16) as u8;
+    position_block[4] = (lon >> 8) as u8;
+    position_block[5] = lon as u8;
+
+    true
+}
+
+/// Decode a compact block filled by [`extract_gga_compact`].
+///
+/// ### Returns
+/// Latitude and longitude in 10^-7 degrees, negative if south or west.
+#[inline]
+pub const fn decode_compact_block(position_block: &[u8; 6]) -> (i32, i32) {
+    (
+        compact_to_degrees_e7(position_block[0], position_block[1], position_block[2], 900_000_000),
+        compact_to_degrees_e7(position_block[3], position_block[4], position_block[5], 1_800_000_000),
+    )
+}
+
+/// Convert a signed 24-bit value in units of `full_scale` / 2^23 to 10^-7 degrees, rounding to nearest.
+#[inline(always)]
+const fn compact_to_degrees_e7(b0: u8, b1: u8, b2: u8, full_scale: u64) -> i32 {
+    // Sign extend from 24 bits
+    let value = i32::from_be_bytes([b0, b1, b2, 0]) >> 8;
+    let degrees = ((value.unsigned_abs() as u64 * full_scale + COMPACT_FULL_SCALE / 2) / COMPACT_FULL_SCALE) as i32;
+    if value < 0 { -degrees } else { degrees }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_NO_FIX, shift_buffer};
+
+    #[test]
+    fn test_compact_no_fix() {
+        let mut position_block = [0; 6];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert!(!extract_gga_compact(&buffer, i, &mut position_block));
+            assert_eq!(position_block, [0; 6]);
+        }
+    }
+
+    #[test]
+    fn test_compact_with_fix() {
+        let mut position_block = [0; 6];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_position_block, expected_e7) in GGA_WITH_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(extract_gga_compact(&buffer, i, &mut position_block));
+                assert_eq!(position_block, *expected_position_block);
+                let (lat, lon) = decode_compact_block(&position_block);
+                assert_eq!([lat, lon], *expected_e7);
+            }
+        }
+    }
+
+    const GGA_WITH_FIX: [(&[u8], [u8; 6], [i32; 2]); 3] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            [0xD8, 0xC9, 0xF3, 0x6C, 0xD6, 0x2C],
+            [-275703299, 1530514812],
+        ),
+        (
+            b"$GPGGA,181501.000,5944.50086,N,10459.16654,W,1,03,2.10,84.6,M,41.1,M,,*42\r\n",
+            [0x54, 0xF7, 0x48, 0xB5, 0x57, 0xDD],
+            [597416782, -1049861026],
+        ),
+        // Clamped to the largest 24-bit value
+        (
+            b"$GNGGA,051200.993,9000.00000,N,18000.00000,W,1,07,2.8,103.4,M,41.1,M,,*5A\r\n",
+            [0x7F, 0xFF, 0xFF, 0x80, 0x00, 0x01],
+            [899999893, -1799999785],
+        ),
+    ];
+}
@@ -0,0 +1,201 @@
+//! DMA write position from down-counting or up-counting transfer counters.
+use crate::buffer::NmeaBuffer;
+use crate::frame::Framer;
+
+/// How a DMA controller reports its progress through a circular buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DmaCounter {
+    /// Counts the bytes remaining down from the buffer length, as the STM32 NDTR and SAM BTCNT registers do.
+    Down,
+    /// Counts the bytes written up from 0, as the nRF EasyDMA `AMOUNT` register and free-running byte counters do.
+    /// Values past the buffer length are taken modulo the length.
+    Up,
+}
+
+/// Converts a DMA counter into the write index of a circular buffer of any length.
+///
+/// [`DmaCursor::ndtr`] gives the equivalent STM32 NDTR value, so [`Framer`], [`SentenceIter`](crate::SentenceIter)
+/// and [`SentenceScanner`](crate::SentenceScanner) work with any controller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DmaCursor {
+    len: usize,
+    counter: DmaCounter,
+}
+
+impl DmaCursor {
+    /// Create a cursor for a buffer of `len` bytes.
+    ///
+    /// A cursor for an empty buffer always reports a write index of 0 and nothing received.
+    #[inline]
+    pub const fn new(len: usize, counter: DmaCounter) -> DmaCursor {
+        DmaCursor { len, counter }
+    }
+
+    /// Length of the buffer in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// If the buffer has no storage.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Index the DMA will write next.
+    #[inline]
+    pub const fn write_index(&self, counter: u32) -> usize {
+        match self.counter {
+            // A down counter is at most the buffer length, and equal to it once reloaded after wrapping
+            DmaCounter::Down => self.wrap(self.len - counter as usize % self.len.saturating_add(1)),
+            DmaCounter::Up => self.wrap(counter as usize),
+        }
+    }
+
+    /// The STM32 NDTR value for the same write index, counting down from the buffer length.
+    ///
+    /// Saturates at `u16::MAX` for buffers longer than the register can count.
+    #[inline]
+    pub const fn ndtr(&self, counter: u32) -> u16 {
+        let remaining = self.len - self.write_index(counter);
+        if remaining > u16::MAX as usize {
+            u16::MAX
+        } else {
+            remaining as u16
+        }
+    }
+
+    /// Length of the data from `sentence_begin` up to the write index, as
+    /// [`calculate_sentence_length`](crate::calculate_sentence_length) for any buffer length.
+    #[inline]
+    pub const fn sentence_length(&self, counter: u32, sentence_begin: usize) -> usize {
+        self.forward(self.wrap(sentence_begin), self.write_index(counter))
+    }
+
+    /// Number of bytes received between two counter snapshots.
+    ///
+    /// ### Arguments
+    /// * `previous_counter` - Counter value at the previous snapshot.
+    /// * `counter` - Current counter value.
+    /// * `wraps` - Number of times the DMA wrapped past the end of the buffer in between, e.g. counted by a transfer
+    ///   complete interrupt. If the write index moved backwards it must have wrapped at least once, so an
+    ///   undercount from the interrupt racing the counter read is taken as one wrap.
+    #[inline]
+    pub const fn received(&self, previous_counter: u32, counter: u32, wraps: usize) -> usize {
+        let previous_index = self.write_index(previous_counter);
+        let index = self.write_index(counter);
+        let forward = self.forward(previous_index, index);
+        // The forward distance already covers the wrap that took the index below its previous value
+        let wraps = if index < previous_index {
+            wraps.saturating_sub(1)
+        } else {
+            wraps
+        };
+        forward.saturating_add(wraps.saturating_mul(self.len))
+    }
+
+    /// Wrap an index into the buffer, or 0 if it is empty.
+    #[inline]
+    const fn wrap(&self, index: usize) -> usize {
+        match index.checked_rem(self.len) {
+            Some(index) => index,
+            None => 0,
+        }
+    }
+
+    /// Distance forward from `from` to `to`, both already wrapped into the buffer.
+    #[inline]
+    const fn forward(&self, from: usize, to: usize) -> usize {
+        if to >= from { to - from } else { self.len - from + to }
+    }
+
+    /// Iterate over the complete sentences from `begin` up to the write index.
+    #[inline]
+    pub fn framer<'a, B: NmeaBuffer + ?Sized>(&self, buffer: &'a B, begin: usize, counter: u32) -> Framer<'a, B> {
+        Framer::with_length(buffer, begin, self.sentence_length(counter, begin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculate_sentence_length;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_down_matches_ndtr() {
+        let cursor = DmaCursor::new(1024, DmaCounter::Down);
+        for ndtr in 1..=1024u16 {
+            assert_eq!(cursor.ndtr(ndtr as u32) % 1024, ndtr % 1024);
+            for sentence_begin in (0..1024).step_by(7) {
+                assert_eq!(
+                    cursor.sentence_length(ndtr as u32, sentence_begin) % 1024,
+                    calculate_sentence_length(ndtr, sentence_begin) % 1024
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_up() {
+        let cursor = DmaCursor::new(300, DmaCounter::Up);
+        assert_eq!(cursor.write_index(0), 0);
+        assert_eq!(cursor.write_index(299), 299);
+        assert_eq!(cursor.write_index(300), 0);
+        assert_eq!(cursor.write_index(1000), 100);
+        assert_eq!(cursor.ndtr(100), 200);
+        assert_eq!(cursor.sentence_length(50, 250), 100);
+        assert_eq!(cursor.received(250, 50, 1), 100);
+        assert_eq!(cursor.received(250, 50, 2), 400);
+        assert_eq!(cursor.received(50, 250, 0), 200);
+        // The wrap was not counted yet
+        assert_eq!(cursor.received(250, 50, 0), 100);
+    }
+
+    #[test]
+    fn test_down_odd_length() {
+        let cursor = DmaCursor::new(300, DmaCounter::Down);
+        assert_eq!(cursor.write_index(300), 0);
+        assert_eq!(cursor.write_index(1), 299);
+        assert_eq!(cursor.ndtr(200), 200);
+        assert_eq!(cursor.received(300, 300, 1), 300);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        for counter in [DmaCounter::Down, DmaCounter::Up] {
+            let cursor = DmaCursor::new(0, counter);
+            assert_eq!(cursor.write_index(u32::MAX), 0);
+            assert_eq!(cursor.ndtr(5), 0);
+            assert_eq!(cursor.sentence_length(5, 10), 0);
+            assert_eq!(cursor.received(1, 5, 3), 0);
+            assert_eq!(cursor.framer(&[][..], 10, 5).next(), None);
+
+            let cursor = DmaCursor::new(usize::MAX, counter);
+            assert_eq!(cursor.ndtr(0), u16::MAX);
+            assert_eq!(cursor.received(1, 1, 2), usize::MAX);
+        }
+        assert_eq!(DmaCursor::new(100_000, DmaCounter::Up).ndtr(0), u16::MAX);
+        assert_eq!(DmaCursor::new(100_000, DmaCounter::Down).ndtr(1), 1);
+    }
+
+    #[test]
+    fn test_framer() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for counter in [DmaCounter::Down, DmaCounter::Up] {
+            let cursor = DmaCursor::new(1024, counter);
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+                let write_index = (i + GGA_WITH_FIX.len()) % 1024;
+                let counter = match counter {
+                    DmaCounter::Down => 1024 - write_index as u32,
+                    DmaCounter::Up => write_index as u32 + 2048,
+                };
+                let mut framer = cursor.framer(&buffer, i, counter);
+                assert_eq!(framer.next(), Some((i, GGA_WITH_FIX.len())));
+                assert_eq!(framer.next(), None);
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Detect and parse DBT (Depth Below Transducer) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a DBT sentence.
+#[inline]
+pub fn is_dbt<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'D'
+        && byte_at(buffer, sentence_begin + 4) == b'B'
+        && byte_at(buffer, sentence_begin + 5) == b'T'
+}
+
+/// Extract the depth below transducer from a DBT sentence in a circular buffer.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the DBT sentence in the buffer.
+/// * `depth_block` - Output buffer where the depth in centimetres will be stored, big-endian (4 bytes).
+///
+/// ### Returns
+/// If the sentence contains a depth in metres.
+#[inline]
+pub fn extract_dbt<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, depth_block: &mut [u8; 4]) -> bool {
+    // Skip feet and f
+    let index = skip_field(buffer, skip_field(buffer, sentence_begin + 7));
+
+    // Check metres field
+    if byte_at(buffer, index) == b',' {
+        return false;
+    }
+
+    let (depth, _) = parse_decimal(buffer, index, 2);
+    depth_block[0] = (depth >> 24) as u8;
+    depth_block[1] = (depth >> 16) as u8;
+    depth_block[2] = (depth >> 8) as u8;
+    depth_block[3] = depth as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_dbt() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, DBT_VALID[0].0, i);
+            assert!(!is_dbt(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_dbt(&buffer, i));
+            assert!(!is_dbt(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_dbt_empty() {
+        let mut depth_block = [0; 4];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, DBT_EMPTY, i);
+            let parsed = extract_dbt(&buffer, i, &mut depth_block);
+            assert!(!parsed);
+            assert_eq!(depth_block, [0; 4]);
+        }
+    }
+
+    #[test]
+    fn test_dbt_valid() {
+        let mut depth_block = [0; 4];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_depth_block) in DBT_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_dbt(&buffer, i, &mut depth_block);
+                assert!(parsed);
+                assert_eq!(depth_block, *expected_depth_block);
+            }
+        }
+    }
+
+    const DBT_EMPTY: &[u8] = b"$SDDBT,,f,,M,,F*28\r\n";
+
+    const DBT_VALID: [(&[u8], [u8; 4]); 2] = [
+        (b"$SDDBT,0036.1,f,0011.0,M,0006.0,F*34\r\n", [0, 0, 4, 76]),
+        (b"$SDDBT,328.08,f,100.00,M,54.68,F*09\r\n", [0, 0, 39, 16]),
+    ];
+}
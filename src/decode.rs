@@ -0,0 +1,138 @@
+//! Host-side decoding of the packed position block.
+use crate::degrees::signed_degrees_e7;
+use crate::fix::GgaFix;
+
+/// Position decoded from the 10-byte block filled by [`extract_gga`](crate::extract_gga).
+///
+/// Angles are unsigned total minutes from the equator or prime meridian, with the hemisphere in `north` and `east`.
+/// They keep the full 10^-5 minute resolution of the sentence rather than rounding to hundredths of a minute.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "pyo3", pyo3::pyclass(get_all, frozen, from_py_object))]
+pub struct DecodedFix {
+    /// Latitude in minutes scaled by 10^5, e.g. 27°34.21973' is 165421973.
+    pub lat_minutes: u32,
+    /// Longitude in minutes scaled by 10^5.
+    pub lon_minutes: u32,
+    /// If the latitude is in the northern hemisphere.
+    pub north: bool,
+    /// If the longitude is in the eastern hemisphere.
+    pub east: bool,
+    /// HDOP in tenths.
+    pub hdop: u8,
+}
+
+impl DecodedFix {
+    /// Latitude in 10^-7 degrees, negative if south.
+    #[inline]
+    pub const fn lat_e7(&self) -> i32 {
+        signed_degrees_e7(self.lat_minutes, self.north)
+    }
+
+    /// Longitude in 10^-7 degrees, negative if west.
+    #[inline]
+    pub const fn lon_e7(&self) -> i32 {
+        signed_degrees_e7(self.lon_minutes, self.east)
+    }
+}
+
+/// Decode a position block filled by [`extract_gga`](crate::extract_gga).
+///
+/// The block layout is:
+/// * `[0..4]` - Latitude as `ddmm.mmmmm` scaled by 10^6, big-endian. Latitudes from about 42.9° wrap.
+/// * `[4..8]` - Longitude as `dddmm.mmmmm` scaled by 10^5, big-endian.
+/// * `[8]` - Flags: bit 1 is set if north, bit 0 if east.
+/// * `[9]` - HDOP in tenths, saturating at 255.
+#[inline]
+pub const fn decode_position_block(position_block: &[u8; 10]) -> DecodedFix {
+    let fix = GgaFix::from_position_block(position_block, 0);
+    DecodedFix {
+        lat_minutes: fix.lat_degrees() * 6_000_000 + fix.lat_minutes() / 10,
+        lon_minutes: fix.lon_degrees() * 6_000_000 + fix.lon_minutes(),
+        north: fix.is_north(),
+        east: fix.is_east(),
+        hdop: fix.hdop,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::degrees::extract_gga_e7;
+    use crate::extract_gga;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_decode_position_block() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_fix) in DECODED.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(extract_gga(&buffer, i, &mut position_block));
+                assert_eq!(decode_position_block(&position_block), *expected_fix);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_decoded_fix_serde() {
+        fn assert_serde<T: serde::Serialize + serde::de::DeserializeOwned>() {}
+        assert_serde::<DecodedFix>();
+    }
+
+    #[test]
+    fn test_decoded_fix_e7() {
+        assert_eq!(DECODED[0].1.lat_e7(), -275703288);
+        assert_eq!(DECODED[0].1.lon_e7(), 1530514878);
+        assert_eq!(DECODED[1].1.lat_e7(), 397416810);
+        assert_eq!(DECODED[1].1.lon_e7(), -1049861090);
+        let fix = DecodedFix {
+            lat_minutes: 1288490189,
+            lon_minutes: u32::MAX,
+            ..DecodedFix::default()
+        };
+        assert_eq!((fix.lat_e7(), fix.lon_e7()), (-i32::MAX, -i32::MAX));
+        let mut e7_block = [0; 9];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_fix) in DECODED.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(extract_gga_e7(&buffer, i, &mut e7_block));
+                assert_eq!(
+                    i32::from_be_bytes(e7_block[..4].try_into().unwrap()),
+                    expected_fix.lat_e7()
+                );
+                assert_eq!(
+                    i32::from_be_bytes(e7_block[4..8].try_into().unwrap()),
+                    expected_fix.lon_e7()
+                );
+            }
+        }
+    }
+
+    const DECODED: [(&[u8], DecodedFix); 2] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            DecodedFix {
+                lat_minutes: 165421973,
+                lon_minutes: 918308927,
+                north: false,
+                east: true,
+                hdop: 28,
+            },
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            DecodedFix {
+                lat_minutes: 238450086,
+                lon_minutes: 629916654,
+                north: true,
+                east: false,
+                hdop: 21,
+            },
+        ),
+    ];
+}
@@ -0,0 +1,173 @@
+//! Signed decimal degree output in units of 10^-7 degrees, as used by u-blox and most telemetry protocols.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Convert minutes scaled by 10^5 to degrees scaled by 10^7, rounding to nearest.
+#[inline]
+pub const fn minutes_e5_to_degrees_e7(minutes: u32) -> u32 {
+    // 10^7 / (60 * 10^5) = 5 / 3
+    ((minutes as u64 * 5 + 1) / 3) as u32
+}
+
+/// Convert minutes scaled by 10^5 to signed degrees scaled by 10^7, clamped to the i32 range so the negation of out
+/// of range input cannot overflow.
+#[inline]
+pub(crate) const fn signed_degrees_e7(minutes: u32, positive: bool) -> i32 {
+    // Rounded as minutes_e5_to_degrees_e7, before its result is truncated to u32
+    let degrees = (minutes as u64 * 5 + 1) / 3;
+    let degrees = if degrees <= i32::MAX as u64 {
+        degrees as i32
+    } else {
+        i32::MAX
+    };
+    if positive { degrees } else { -degrees }
+}
+
+/// Convert a `ddmm.mmmmm` or `dddmm.mmmmm` value scaled by 10^5 to minutes scaled by 10^5.
+#[inline(always)]
+pub(crate) const fn total_minutes(degrees_minutes: u32) -> u32 {
+    degrees_minutes / 10_000_000 * 6_000_000 + degrees_minutes % 10_000_000
+}
+
+/// Read the latitude and longitude of a GGA sentence as minutes scaled by 10^5, which fit a u32 over the whole range
+/// unlike the `ddmm.mmmmm` value scaled by 10^6 that [`extract_gga`](crate::extract_gga) stores.
+///
+/// ### Returns
+/// The latitude and longitude minutes, without hemispheres.
+#[inline]
+pub(crate) fn total_minutes_at<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> (u32, u32) {
+    let (lat, end) = parse_decimal(buffer, sentence_begin + 18, 5);
+    let (lon, _) = parse_decimal(buffer, skip_field(buffer, skip_field(buffer, end)), 5);
+    (total_minutes(lat), total_minutes(lon))
+}
+
+/// Extract position data from a GGA sentence in a circular buffer as signed decimal degrees.
+///
+/// The position block layout is:
+/// * `[0..4]` - Latitude in 10^-7 degrees, signed big-endian. Negative if south.
+/// * `[4..8]` - Longitude in 10^-7 degrees, signed big-endian. Negative if west.
+/// * `[8]` - HDOP in tenths, saturating at 255.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (9 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix.
+#[inline]
+pub fn extract_gga_e7<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, position_block: &mut [u8; 9]) -> bool {
+    // Check time field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        // No time field, assume no fix
+        return false;
+    }
+
+    // Check latitude field
+    if byte_at(buffer, sentence_begin + 18) == b',' {
+        // No latitude field, no fix
+        return false;
+    }
+
+    let (lat, end) = parse_decimal(buffer, sentence_begin + 18, 5);
+    let lat_hemisphere = skip_field(buffer, end);
+    let lat = signed_degrees_e7(total_minutes(lat), byte_at(buffer, lat_hemisphere) != b'S');
+    position_block[0] = (lat >> 24) as u8;
+    position_block[1] = (lat >> 16) as u8;
+    position_block[2] = (lat >> 8) as u8;
+    position_block[3] = lat as u8;
+
+    let (lon, end) = parse_decimal(buffer, skip_field(buffer, lat_hemisphere), 5);
+    let lon_hemisphere = skip_field(buffer, end);
+    let lon = signed_degrees_e7(total_minutes(lon), byte_at(buffer, lon_hemisphere) != b'W');
+    position_block[4] = (lon >> 24) as u8;
+    position_block[5] = (lon >> 16) as u8;
+    position_block[6] = (lon >> 8) as u8;
+    position_block[7] = lon as u8;
+
+    // Skip fix quality and satellites used
+    let hdop_begin = skip_field(buffer, skip_field(buffer, skip_field(buffer, lon_hemisphere)));
+    let (hdop, _) = parse_decimal(buffer, hdop_begin, 1);
+    position_block[8] = if hdop < 256 { hdop as u8 } else { 255 };
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_minutes_e5_to_degrees_e7() {
+        assert_eq!(minutes_e5_to_degrees_e7(0), 0);
+        assert_eq!(minutes_e5_to_degrees_e7(6_000_000), 10_000_000);
+        assert_eq!(minutes_e5_to_degrees_e7(1), 2);
+        assert_eq!(minutes_e5_to_degrees_e7(2), 3);
+        assert_eq!(minutes_e5_to_degrees_e7(3), 5);
+        assert_eq!(minutes_e5_to_degrees_e7(1_080_000_000), 1_800_000_000);
+    }
+
+    #[test]
+    fn test_e7_no_fix() {
+        let mut position_block = [0; 9];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n", i);
+            let parsed = extract_gga_e7(&buffer, i, &mut position_block);
+            assert!(!parsed);
+            assert_eq!(position_block, [0; 9]);
+        }
+    }
+
+    #[test]
+    fn test_e7_with_fix() {
+        let mut position_block = [0; 9];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_lat, expected_lon, expected_hdop) in GGA_E7.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_e7(&buffer, i, &mut position_block);
+                assert!(parsed);
+                assert_eq!(
+                    i32::from_be_bytes(position_block[..4].try_into().unwrap()),
+                    *expected_lat
+                );
+                assert_eq!(
+                    i32::from_be_bytes(position_block[4..8].try_into().unwrap()),
+                    *expected_lon
+                );
+                assert_eq!(position_block[8], *expected_hdop);
+            }
+        }
+    }
+
+    const GGA_E7: [(&[u8], i32, i32, u8); 4] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            -275703288,
+            1530514878,
+            28,
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            397416810,
+            -1049861090,
+            21,
+        ),
+        // Latitudes beyond the range of the ddmm.mmmmm position block
+        (
+            b"$GNGGA,051200.993,8959.99999,N,17959.99999,W,4,12,12.5,103.4,M,41.1,M,,*69\r\n",
+            899999998,
+            -1799999998,
+            125,
+        ),
+        // Digits beyond the i32 range clamp instead of overflowing on negation
+        (
+            b"$GNGGA,051200.993,21444.90189,S,99999.99999,W,4,12,12.5,103.4,M,41.1,M,,*69\r\n",
+            -2147483647,
+            -2147483647,
+            125,
+        ),
+    ];
+}
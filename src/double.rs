@@ -0,0 +1,114 @@
+//! Double-buffer (ping-pong) DMA support.
+use crate::buffer::NmeaBuffer;
+
+/// Two DMA double-buffer memories read as one circular buffer, first then second.
+///
+/// In double-buffer mode the DMA fills one memory, switches to the other, and switches back, which is the same
+/// byte order as a circular buffer of twice the size. Sentences that cross from one memory to the other are stitched
+/// transparently, so every extractor and [`SentenceScanner`](crate::SentenceScanner) accepts a `DoubleBuffer`.
+#[derive(Clone, Copy, Debug)]
+pub struct DoubleBuffer<'a, const N: usize> {
+    first: &'a [u8; N],
+    second: &'a [u8; N],
+}
+
+impl<'a, const N: usize> DoubleBuffer<'a, N> {
+    /// Combine the two memories, in the order the DMA fills them.
+    ///
+    /// The combined length must fit a 16-bit NDTR, so `N` is at most 32767. Larger `N` fails to compile.
+    #[inline]
+    pub const fn new(first: &'a [u8; N], second: &'a [u8; N]) -> DoubleBuffer<'a, N> {
+        const { assert!(N > 0 && N <= u16::MAX as usize / 2, "memories must be 1 to 32767 bytes") };
+        DoubleBuffer { first, second }
+    }
+
+    /// Convert the NDTR of the active memory into the NDTR of the combined buffer.
+    ///
+    /// ### Arguments
+    /// * `active` - Memory the DMA is currently writing, 0 for the first or 1 for the second, e.g. the CT bit.
+    /// * `ndtr` - Current value of the DMA NDTR register, counting down from `N`. Spurious values above `N` are
+    ///   clamped to it.
+    #[inline]
+    pub const fn ndtr(active: u8, ndtr: u16) -> u16 {
+        const { assert!(N <= u16::MAX as usize / 2, "memories must be at most 32767 bytes") };
+        let ndtr = if ndtr > N as u16 { N as u16 } else { ndtr };
+        if active == 0 { ndtr + N as u16 } else { ndtr }
+    }
+}
+
+impl<const N: usize> NmeaBuffer for DoubleBuffer<'_, N> {
+    #[inline(always)]
+    fn at(&self, index: usize) -> u8 {
+        let index = index % (2 * N);
+        if index < N {
+            self.first[index]
+        } else {
+            self.second[index - N]
+        }
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        2 * N
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+    use crate::{SentenceScanner, SentenceType, extract_gga};
+
+    #[test]
+    fn test_double_buffer() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut expected_position_block = [0; 10];
+        let mut position_block = [0; 10];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            assert!(extract_gga(&buffer, i, &mut expected_position_block));
+            let (first, second) = buffer.split_at(512);
+            let double = DoubleBuffer::<512>::new(first.try_into().unwrap(), second.try_into().unwrap());
+            assert!(extract_gga(&double, i, &mut position_block));
+            assert_eq!(position_block, expected_position_block);
+        }
+    }
+
+    #[test]
+    fn test_ndtr_out_of_range() {
+        assert_eq!(DoubleBuffer::<512>::ndtr(0, u16::MAX), 1024);
+        assert_eq!(DoubleBuffer::<512>::ndtr(1, 513), 512);
+        assert_eq!(DoubleBuffer::<512>::ndtr(0, 5), 517);
+    }
+
+    #[test]
+    fn test_double_buffer_scanner() {
+        let mut memories = [[0u8; 512]; 2];
+        let mut active = 0;
+        let mut ndtr: u16 = 512;
+        let mut scanner = SentenceScanner::new();
+        let mut ggas = 0;
+        for _ in 0..20 {
+            for chunk in STREAM.chunks(41) {
+                for &b in chunk {
+                    memories[active][512 - ndtr as usize] = b;
+                    ndtr -= 1;
+                    if ndtr == 0 {
+                        active ^= 1;
+                        ndtr = 512;
+                    }
+                }
+                let double = DoubleBuffer::new(&memories[0], &memories[1]);
+                let combined_ndtr = DoubleBuffer::<512>::ndtr(active as u8, ndtr);
+                for sentence in scanner.on_idle(&double, combined_ndtr) {
+                    ggas += (sentence.sentence_type == SentenceType::Gga) as usize;
+                }
+            }
+        }
+        assert_eq!(ggas, 20 * 2);
+    }
+
+    const STREAM: &[u8] = b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n";
+}
@@ -0,0 +1,98 @@
+//! Detect and parse DPT (Depth of Water) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, parse_signed_decimal, skip_field};
+
+/// Determine if the sentence is a DPT sentence.
+#[inline]
+pub fn is_dpt<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'D'
+        && byte_at(buffer, sentence_begin + 4) == b'P'
+        && byte_at(buffer, sentence_begin + 5) == b'T'
+}
+
+/// Extract the depth and transducer offset from a DPT sentence in a circular buffer.
+///
+/// The depth block layout is:
+/// * `[0..4]` - Depth below transducer in centimetres, big-endian.
+/// * `[4..6]` - Transducer offset in centimetres, signed big-endian. Positive is the distance from the transducer
+///   to the waterline, negative is the distance from the transducer to the keel. 0 if the field is empty.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the DPT sentence in the buffer.
+/// * `depth_block` - Output buffer where parsed data will be stored (6 bytes).
+///
+/// ### Returns
+/// If the sentence contains a depth.
+#[inline]
+pub fn extract_dpt<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, depth_block: &mut [u8; 6]) -> bool {
+    // Check depth field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        return false;
+    }
+
+    let (depth, end) = parse_decimal(buffer, sentence_begin + 7, 2);
+    depth_block[0] = (depth >> 24) as u8;
+    depth_block[1] = (depth >> 16) as u8;
+    depth_block[2] = (depth >> 8) as u8;
+    depth_block[3] = depth as u8;
+
+    let (offset, _) = parse_signed_decimal(buffer, skip_field(buffer, end), 2);
+    let offset = offset.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    depth_block[4] = (offset >> 8) as u8;
+    depth_block[5] = offset as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_dpt() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, DPT_VALID[0].0, i);
+            assert!(!is_dpt(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_dpt(&buffer, i));
+            assert!(!is_dpt(&buffer, (i + 1) & 1023));
+            assert!(!crate::is_dbt(&buffer, i));
+        }
+    }
+
+    #[test]
+    fn test_dpt_empty() {
+        let mut depth_block = [0; 6];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, DPT_EMPTY, i);
+            let parsed = extract_dpt(&buffer, i, &mut depth_block);
+            assert!(!parsed);
+            assert_eq!(depth_block, [0; 6]);
+        }
+    }
+
+    #[test]
+    fn test_dpt_valid() {
+        let mut depth_block = [0; 6];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_depth_block) in DPT_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_dpt(&buffer, i, &mut depth_block);
+                assert!(parsed);
+                assert_eq!(depth_block, *expected_depth_block);
+            }
+        }
+    }
+
+    const DPT_EMPTY: &[u8] = b"$SDDPT,,,*7B\r\n";
+
+    const DPT_VALID: [(&[u8], [u8; 6]); 2] = [
+        (b"$SDDPT,11.0,0.5*62\r\n", [0, 0, 4, 76, 0, 50]),
+        // With maximum range scale
+        (b"$SDDPT,2.35,-1.20,100.0*7E\r\n", [0, 0, 0, 235, 255, 136]),
+    ];
+}
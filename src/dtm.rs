@@ -0,0 +1,149 @@
+//! Detect and parse DTM (Datum Reference) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, parse_signed_decimal, skip_field};
+
+/// Determine if the sentence is a DTM sentence.
+#[inline]
+pub fn is_dtm<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'D'
+        && byte_at(buffer, sentence_begin + 4) == b'T'
+        && byte_at(buffer, sentence_begin + 5) == b'M'
+}
+
+/// Extract the local datum and its offsets from a DTM sentence in a circular buffer.
+///
+/// The datum block layout is:
+/// * `[0..3]` - Local datum code as ASCII, e.g. `W84` for WGS 84 or `999` for a user defined datum.
+/// * `[3..7]` - Latitude offset in hundred-thousandths of a minute, signed big-endian. Negative if south.
+/// * `[7..11]` - Longitude offset in hundred-thousandths of a minute, signed big-endian. Negative if west.
+/// * `[11..15]` - Altitude offset in millimetres, signed big-endian.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the DTM sentence in the buffer.
+/// * `datum_block` - Output buffer where parsed data will be stored (15 bytes).
+///
+/// ### Returns
+/// If the sentence contains a local datum code.
+#[inline]
+pub fn extract_dtm<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, datum_block: &mut [u8; 15]) -> bool {
+    // Check local datum field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        return false;
+    }
+
+    // Local datum code is 3 characters
+    datum_block[0] = byte_at(buffer, sentence_begin + 7);
+    datum_block[1] = byte_at(buffer, sentence_begin + 8);
+    datum_block[2] = byte_at(buffer, sentence_begin + 9);
+
+    // Skip the variable width subdivision code
+    let index = skip_field(buffer, skip_field(buffer, sentence_begin + 7));
+
+    let (lat_offset, end) = parse_decimal(buffer, index, 5);
+    let lat_hemisphere = skip_field(buffer, end);
+    let lat_offset = if lat_offset <= i32::MAX as u32 {
+        lat_offset as i32
+    } else {
+        i32::MAX
+    };
+    let lat_offset = if byte_at(buffer, lat_hemisphere) == b'S' {
+        -lat_offset
+    } else {
+        lat_offset
+    };
+    datum_block[3] = (lat_offset >> 24) as u8;
+    datum_block[4] = (lat_offset >> 16) as u8;
+    datum_block[5] = (lat_offset >> 8) as u8;
+    datum_block[6] = lat_offset as u8;
+
+    let (lon_offset, end) = parse_decimal(buffer, skip_field(buffer, lat_hemisphere), 5);
+    let lon_hemisphere = skip_field(buffer, end);
+    let lon_offset = if lon_offset <= i32::MAX as u32 {
+        lon_offset as i32
+    } else {
+        i32::MAX
+    };
+    let lon_offset = if byte_at(buffer, lon_hemisphere) == b'W' {
+        -lon_offset
+    } else {
+        lon_offset
+    };
+    datum_block[7] = (lon_offset >> 24) as u8;
+    datum_block[8] = (lon_offset >> 16) as u8;
+    datum_block[9] = (lon_offset >> 8) as u8;
+    datum_block[10] = lon_offset as u8;
+
+    let (alt_offset, _) = parse_signed_decimal(buffer, skip_field(buffer, lon_hemisphere), 3);
+    datum_block[11] = (alt_offset >> 24) as u8;
+    datum_block[12] = (alt_offset >> 16) as u8;
+    datum_block[13] = (alt_offset >> 8) as u8;
+    datum_block[14] = alt_offset as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_dtm() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, DTM_VALID[0].0, i);
+            assert!(!is_dtm(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_dtm(&buffer, i));
+            assert!(!is_dtm(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_dtm_empty() {
+        let mut datum_block = [0; 15];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, DTM_EMPTY, i);
+            let parsed = extract_dtm(&buffer, i, &mut datum_block);
+            assert!(!parsed);
+            assert_eq!(datum_block, [0; 15]);
+        }
+    }
+
+    #[test]
+    fn test_dtm_valid() {
+        let mut datum_block = [0; 15];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_datum_block) in DTM_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_dtm(&buffer, i, &mut datum_block);
+                assert!(parsed);
+                assert_eq!(datum_block, *expected_datum_block);
+            }
+        }
+    }
+
+    const DTM_EMPTY: &[u8] = b"$GPDTM,,,,,,,,*4A\r\n";
+
+    const DTM_VALID: [(&[u8], [u8; 15]); 4] = [
+        (
+            b"$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F\r\n",
+            [87, 56, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ),
+        (
+            b"$GPDTM,999,CH95,0.08,N,0.07,E,-47.7,W84*1C\r\n",
+            [57, 57, 57, 0, 0, 31, 64, 0, 0, 27, 88, 255, 255, 69, 172],
+        ),
+        (
+            b"$GNDTM,P90,,0.00012,S,1.5,W,2.125,W84*7F\r\n",
+            [80, 57, 48, 255, 255, 255, 244, 255, 253, 182, 16, 0, 0, 8, 77],
+        ),
+        // Offsets beyond the i32 range clamp instead of overflowing on negation
+        (
+            b"$GPDTM,999,,21474.83648,S,99999.99999,W,0.0,W84*1C\r\n",
+            [57, 57, 57, 128, 0, 0, 1, 128, 0, 0, 1, 0, 0, 0, 0],
+        ),
+    ];
+}
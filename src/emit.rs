@@ -0,0 +1,418 @@
+//! Re-emission of parsed fixes and generation of synthetic fixes as canonical NMEA sentences.
+use crate::checksum::finalize_sentence;
+use crate::extended::ExtendedFix;
+use crate::fix::GgaFix;
+use crate::parse::MAX_SENTENCE_LENGTH;
+use crate::time::{PackedDate, PackedTime};
+
+/// Write `value` as `width` ASCII decimal digits, zero padded.
+#[inline(always)]
+pub(crate) fn write_digits(out: &mut [u8], mut value: u32, width: usize) {
+    for i in (0..width).rev() {
+        out[i] = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
+
+/// Number of decimal digits in `value`, at least 1.
+#[inline(always)]
+fn digit_count(mut value: u32) -> usize {
+    let mut count = 1;
+    while value >= 10 {
+        value /= 10;
+        count += 1;
+    }
+    count
+}
+
+/// Write a value scaled by 10 with one decimal, e.g. 1034 as `103.4`, returning the index after it.
+#[inline(always)]
+pub(crate) fn write_tenths(sentence: &mut [u8], mut index: usize, value: i32) -> usize {
+    if value < 0 {
+        sentence[index] = b'-';
+        index += 1;
+    }
+    let value = value.unsigned_abs();
+    let width = digit_count(value / 10);
+    write_digits(&mut sentence[index..], value / 10, width);
+    index += width;
+    sentence[index] = b'.';
+    sentence[index + 1] = b'0' + (value % 10) as u8;
+    index + 2
+}
+
+/// Write a value scaled by 100 with two decimals, e.g. 52 as `0.52`, returning the index after it.
+#[inline(always)]
+fn write_hundredths(sentence: &mut [u8], index: usize, value: u32) -> usize {
+    let width = digit_count(value / 100);
+    write_digits(&mut sentence[index..], value / 100, width);
+    sentence[index + width] = b'.';
+    write_digits(&mut sentence[index + width + 1..], value % 100, 2);
+    index + width + 3
+}
+
+/// Write a time of day as `hhmmss.sss`.
+#[inline(always)]
+fn write_time(out: &mut [u8], time: PackedTime) {
+    write_digits(out, time.hour() as u32, 2);
+    write_digits(&mut out[2..], time.minute() as u32, 2);
+    write_digits(&mut out[4..], time.second() as u32, 2);
+    out[6] = b'.';
+    write_digits(&mut out[7..], time.millisecond() as u32, 3);
+}
+
+/// Generate a GGA sentence with a correct checksum from an extracted fix, e.g. to feed a device under test.
+///
+/// The layout is `$GNGGA,hhmmss.sss,ddmm.mmmmm,N,dddmm.mmmmm,E,q,00,h.h,,M,,M,,*hh\r\n`. The fix does not carry
+/// the satellite count or altitude, so they are written as `00` and left empty.
+///
+/// ### Arguments
+/// * `fix` - The fix to encode, as from [`extract_gga_struct`](crate::extract_gga_struct).
+/// * `time` - UTC time of the fix.
+/// * `out` - Output buffer where the sentence will be stored.
+///
+/// ### Returns
+/// Length of the sentence including `$` and `\r\n`, or 0 if it does not fit in `out`.
+#[inline]
+pub fn encode_gga(fix: &GgaFix, time: PackedTime, out: &mut [u8]) -> usize {
+    let mut sentence = [0; MAX_SENTENCE_LENGTH];
+    sentence[0..7].copy_from_slice(b"$GNGGA,");
+    write_time(&mut sentence[7..], time);
+    sentence[17] = b',';
+
+    // ddmm.mmmmm from the total minutes, `lat` wraps from about 42.95°
+    let lat_minutes = fix.lat_total_minutes % 6_000_000;
+    write_digits(
+        &mut sentence[18..],
+        fix.lat_total_minutes / 6_000_000 * 100 + lat_minutes / 100_000,
+        4,
+    );
+    sentence[22] = b'.';
+    write_digits(&mut sentence[23..], lat_minutes % 100_000, 5);
+    sentence[28] = b',';
+    sentence[29] = if fix.flags & 0b10 != 0 { b'N' } else { b'S' };
+    sentence[30] = b',';
+
+    // dddmm.mmmmm
+    write_digits(&mut sentence[31..], fix.lon / 100_000, 5);
+    sentence[36] = b'.';
+    write_digits(&mut sentence[37..], fix.lon % 100_000, 5);
+    sentence[42] = b',';
+    sentence[43] = if fix.flags & 0b01 != 0 { b'E' } else { b'W' };
+    sentence[44] = b',';
+
+    sentence[45] = b'0' + fix.quality % 10;
+    sentence[46..50].copy_from_slice(b",00,");
+
+    // Altitude, geoid separation and differential fields are empty
+    let index = write_tenths(&mut sentence, 50, fix.hdop as i32);
+    sentence[index..index + 8].copy_from_slice(b",,M,,M,,");
+    let length = finalize_sentence(&mut sentence, index + 8);
+
+    let Some(out) = out.get_mut(..length) else {
+        return 0;
+    };
+    out.copy_from_slice(&sentence[..length]);
+    length
+}
+
+/// Generate a RMC sentence with a correct checksum from a motion block, e.g. to feed a device under test.
+///
+/// The layout is `$GNRMC,hhmmss.sss,A,ddmm.mmmmm,N,dddmm.mmmmm,E,s.ss,c.cc,ddmmyy,,,A*hh\r\n`, which
+/// [`extract_rmc`](crate::extract_rmc) parses back to the same motion block. The status is `V` and the mode `N` if
+/// the validity flag is clear.
+///
+/// ### Arguments
+/// * `motion_block` - Position, speed and course in the layout of [`extract_rmc`](crate::extract_rmc).
+/// * `date` - UTC date of the fix.
+/// * `time` - UTC time of the fix.
+/// * `out` - Output buffer where the sentence will be stored.
+///
+/// ### Returns
+/// Length of the sentence including `$` and `\r\n`, or 0 if it does not fit in `out`.
+#[inline]
+pub fn encode_rmc(motion_block: &[u8; 13], date: PackedDate, time: PackedTime, out: &mut [u8]) -> usize {
+    let mut sentence = [0; MAX_SENTENCE_LENGTH];
+    let length = write_rmc(
+        &mut sentence,
+        // ddmm.mmmmm, the motion block holds it scaled by 10^6
+        u32::from_be_bytes([motion_block[0], motion_block[1], motion_block[2], motion_block[3]]) / 10,
+        u32::from_be_bytes([motion_block[4], motion_block[5], motion_block[6], motion_block[7]]),
+        motion_block[8],
+        u16::from_be_bytes([motion_block[9], motion_block[10]]),
+        u16::from_be_bytes([motion_block[11], motion_block[12]]),
+        date,
+        time,
+    );
+
+    let Some(out) = out.get_mut(..length) else {
+        return 0;
+    };
+    out.copy_from_slice(&sentence[..length]);
+    length
+}
+
+/// Write a RMC sentence with a position, see [`encode_rmc`].
+///
+/// ### Arguments
+/// * `lat` - Latitude as `ddmm.mmmmm` scaled by 10^5.
+/// * `lon` - Longitude as `dddmm.mmmmm` scaled by 10^5.
+/// * `flags` - Bit 2 is set if valid, bit 1 if north, bit 0 if east.
+/// * `sog` - Speed over ground in hundredths of a knot.
+/// * `cog` - Course over ground in hundredths of a degree.
+///
+/// ### Returns
+/// Length of the sentence including `$` and `\r\n`.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub(crate) fn write_rmc(
+    sentence: &mut [u8; MAX_SENTENCE_LENGTH],
+    lat: u32,
+    lon: u32,
+    flags: u8,
+    sog: u16,
+    cog: u16,
+    date: PackedDate,
+    time: PackedTime,
+) -> usize {
+    let valid = flags & 0b100 != 0;
+    sentence[0..7].copy_from_slice(b"$GNRMC,");
+    write_time(&mut sentence[7..], time);
+    sentence[17] = b',';
+    sentence[18] = if valid { b'A' } else { b'V' };
+    sentence[19] = b',';
+
+    // ddmm.mmmmm
+    write_digits(&mut sentence[20..], lat / 100_000, 4);
+    sentence[24] = b'.';
+    write_digits(&mut sentence[25..], lat % 100_000, 5);
+    sentence[30] = b',';
+    sentence[31] = if flags & 0b10 != 0 { b'N' } else { b'S' };
+    sentence[32] = b',';
+
+    // dddmm.mmmmm
+    write_digits(&mut sentence[33..], lon / 100_000, 5);
+    sentence[38] = b'.';
+    write_digits(&mut sentence[39..], lon % 100_000, 5);
+    sentence[44] = b',';
+    sentence[45] = if flags & 0b01 != 0 { b'E' } else { b'W' };
+    sentence[46] = b',';
+
+    // Speed in knots and course in degrees
+    let mut index = write_hundredths(sentence, 47, sog as u32);
+    sentence[index] = b',';
+    index = write_hundredths(sentence, index + 1, cog as u32);
+    sentence[index] = b',';
+
+    // ddmmyy, then empty magnetic variation
+    write_digits(&mut sentence[index + 1..], date.day as u32 % 100, 2);
+    write_digits(&mut sentence[index + 3..], date.month as u32 % 100, 2);
+    write_digits(&mut sentence[index + 5..], date.year as u32 % 100, 2);
+    index += 7;
+    sentence[index..index + 3].copy_from_slice(b",,,");
+    sentence[index + 3] = if valid { b'A' } else { b'N' };
+    finalize_sentence(sentence, index + 4)
+}
+
+/// Serialize a fix as a canonical GGA sentence with a correct checksum.
+///
+/// Every field has a fixed precision, so the sentence follows the layout read by [`extract_gga`](crate::extract_gga):
+/// `$GNGGA,hhmmss.000,ddmm.mmmmm,N,dddmm.mmmmm,E,q,ss,h.h,a.a,M,,,,*hh\r\n`. The geoid separation and differential
+/// fields are left empty.
+///
+/// ### Arguments
+/// * `fix` - The fix to serialize, e.g. from [`decode_extended_block`](crate::decode_extended_block).
+/// * `sentence` - Output buffer where the sentence will be stored.
+///
+/// ### Returns
+/// Length of the sentence including `$` and `\r\n`.
+#[inline]
+pub fn emit_gga(fix: &ExtendedFix, sentence: &mut [u8; MAX_SENTENCE_LENGTH]) -> usize {
+    sentence[0..7].copy_from_slice(b"$GNGGA,");
+
+    // hhmmss.000
+    let time = fix.time % 86_400;
+    write_digits(&mut sentence[7..], time / 3600, 2);
+    write_digits(&mut sentence[9..], time / 60 % 60, 2);
+    write_digits(&mut sentence[11..], time % 60, 2);
+    sentence[13..18].copy_from_slice(b".000,");
+
+    // ddmm.mmmmm
+    let lat = fix.position.lat_minutes;
+    write_digits(&mut sentence[18..], lat / 6_000_000, 2);
+    write_digits(&mut sentence[20..], lat % 6_000_000 / 100_000, 2);
+    sentence[22] = b'.';
+    write_digits(&mut sentence[23..], lat % 100_000, 5);
+    sentence[28] = b',';
+    sentence[29] = if fix.position.north { b'N' } else { b'S' };
+    sentence[30] = b',';
+
+    // dddmm.mmmmm
+    let lon = fix.position.lon_minutes;
+    write_digits(&mut sentence[31..], lon / 6_000_000, 3);
+    write_digits(&mut sentence[34..], lon % 6_000_000 / 100_000, 2);
+    sentence[36] = b'.';
+    write_digits(&mut sentence[37..], lon % 100_000, 5);
+    sentence[42] = b',';
+    sentence[43] = if fix.position.east { b'E' } else { b'W' };
+    sentence[44] = b',';
+
+    // Quality is a single digit, satellites two
+    sentence[45] = b'0' + fix.quality % 10;
+    sentence[46] = b',';
+    write_digits(&mut sentence[47..], fix.satellites as u32 % 100, 2);
+    sentence[49] = b',';
+
+    let mut index = write_tenths(sentence, 50, fix.position.hdop as i32);
+    sentence[index] = b',';
+    index = write_tenths(sentence, index + 1, fix.altitude);
+    sentence[index..index + 6].copy_from_slice(b",M,,,,");
+    finalize_sentence(sentence, index + 6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::verify_checksum;
+    use crate::decode::DecodedFix;
+    use crate::extended::{decode_extended_block, extract_gga_extended};
+    use crate::fix::extract_gga_struct;
+    use crate::rmc::extract_rmc;
+    use crate::tests::{GGA_HIGH_LATITUDE, shift_buffer};
+
+    #[test]
+    fn test_emit_gga() {
+        let mut sentence = [0; MAX_SENTENCE_LENGTH];
+        let mut extended_block = [0; 16];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (input, expected_sentence) in EMITTED.iter() {
+            shift_buffer(&mut buffer, input, 0);
+            assert!(extract_gga_extended(&buffer, 0, &mut extended_block));
+            let fix = decode_extended_block(&extended_block).unwrap();
+            let length = emit_gga(&fix, &mut sentence);
+            assert_eq!(&sentence[..length], *expected_sentence);
+
+            // The emitted sentence parses back to the same fix
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, &sentence[..length], i);
+                assert!(verify_checksum(&buffer, i, length));
+                assert!(extract_gga_extended(&buffer, i, &mut extended_block));
+                assert_eq!(decode_extended_block(&extended_block), Some(fix));
+            }
+        }
+    }
+
+    #[test]
+    fn test_emit_gga_longest() {
+        let fix = ExtendedFix {
+            position: DecodedFix {
+                lat_minutes: 539_999_999,
+                lon_minutes: 1_079_999_999,
+                north: false,
+                east: false,
+                hdop: 255,
+            },
+            time: 86_399,
+            altitude: -1_048_576,
+            satellites: 63,
+            quality: 8,
+        };
+        let mut sentence = [0; MAX_SENTENCE_LENGTH];
+        let length = emit_gga(&fix, &mut sentence);
+        assert_eq!(
+            &sentence[..length],
+            b"$GNGGA,235959.000,8959.99999,S,17959.99999,W,8,63,25.5,-104857.6,M,,,,*3B\r\n"
+        );
+    }
+
+    #[test]
+    fn test_encode_gga() {
+        let mut sentence = [0; MAX_SENTENCE_LENGTH];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (input, time, expected_sentence) in ENCODED.iter() {
+            shift_buffer(&mut buffer, input, 0);
+            let fix = extract_gga_struct(&buffer, 0).unwrap();
+            let length = encode_gga(&fix, *time, &mut sentence);
+            assert_eq!(&sentence[..length], *expected_sentence);
+            assert_eq!(encode_gga(&fix, *time, &mut sentence[..length - 1]), 0);
+
+            // The generated sentence parses back to the same fix
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, &sentence[..length], i);
+                assert!(verify_checksum(&buffer, i, length));
+                assert_eq!(extract_gga_struct(&buffer, i), Some(fix));
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_rmc() {
+        let mut sentence = [0; MAX_SENTENCE_LENGTH];
+        let mut motion_block = [0; 13];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (input, date, time, expected_sentence) in ENCODED_RMC.iter() {
+            shift_buffer(&mut buffer, input, 0);
+            let valid = extract_rmc(&buffer, 0, &mut motion_block);
+            let length = encode_rmc(&motion_block, *date, *time, &mut sentence);
+            assert_eq!(&sentence[..length], *expected_sentence);
+            assert_eq!(encode_rmc(&motion_block, *date, *time, &mut sentence[..length - 1]), 0);
+
+            // The generated sentence parses back to the same motion block
+            let mut parsed_block = [0; 13];
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, &sentence[..length], i);
+                assert!(verify_checksum(&buffer, i, length));
+                assert_eq!(extract_rmc(&buffer, i, &mut parsed_block), valid);
+                assert_eq!(parsed_block, motion_block);
+            }
+        }
+    }
+
+    const ENCODED_RMC: [(&[u8], PackedDate, PackedTime, &[u8]); 2] = [
+        (
+            b"$GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n",
+            PackedDate::new(2025, 6, 15),
+            PackedTime::new(5, 12, 0, 993),
+            b"$GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n",
+        ),
+        (
+            b"$GNRMC,181501.000,V,3944.50086,N,10459.16654,W,12.5,7,010126,,,N*55\r\n",
+            PackedDate::new(2026, 1, 1),
+            PackedTime::new(18, 15, 1, 0),
+            b"$GNRMC,181501.000,V,3944.50086,N,10459.16654,W,12.50,7.00,010126,,,N*4B\r\n",
+        ),
+    ];
+
+    const ENCODED: [(&[u8], PackedTime, &[u8]); 3] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            PackedTime::new(5, 12, 0, 993),
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,00,2.8,,M,,M,,*6C\r\n",
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,12.1,84.6,M,41.1,M,,*6E\r\n",
+            PackedTime::new(18, 15, 1, 0),
+            b"$GNGGA,181501.000,3944.50086,N,10459.16654,W,2,00,12.1,,M,,M,,*55\r\n",
+        ),
+        (
+            GGA_HIGH_LATITUDE,
+            PackedTime::new(12, 0, 0, 0),
+            b"$GNGGA,120000.000,5130.12345,N,00007.65432,W,1,00,0.9,,M,,M,,*6D\r\n",
+        ),
+    ];
+
+    const EMITTED: [(&[u8], &[u8]); 3] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            b"$GNGGA,051200.000,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,,,,*0D\r\n",
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            b"$GNGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.1,-12.3,M,,,,*19\r\n",
+        ),
+        (
+            b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,4,12,39.9,12345.6,M,41.1,M,1.0,1023*55\r\n",
+            b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,4,12,25.5,12345.6,M,,,,*2C\r\n",
+        ),
+    ];
+}
@@ -0,0 +1,72 @@
+//! Destination of the position data parsed by [`extract_gga_into`](crate::extract_gga_into).
+
+/// Writes parsed position data into an application's wire format.
+pub trait PositionEncoder {
+    /// Receive the position data of a GGA sentence containing a fix.
+    ///
+    /// ### Arguments
+    /// * `lat` - Latitude as `ddmm.mmmmm` scaled by 10^6.
+    /// * `lon` - Longitude as `dddmm.mmmmm` scaled by 10^5.
+    /// * `flags` - Bit 1 is set if north, bit 0 if east.
+    /// * `hdop` - HDOP in tenths, saturating at 255.
+    fn encode(&mut self, lat: u32, lon: u32, flags: u8, hdop: u8);
+}
+
+/// The position block of [`extract_gga`](crate::extract_gga).
+impl PositionEncoder for [u8; 10] {
+    #[inline(always)]
+    fn encode(&mut self, lat: u32, lon: u32, flags: u8, hdop: u8) {
+        self[0] = (lat >> 24) as u8;
+        self[1] = (lat >> 16) as u8;
+        self[2] = (lat >> 8) as u8;
+        self[3] = lat as u8;
+        self[4] = (lon >> 24) as u8;
+        self[5] = (lon >> 16) as u8;
+        self[6] = (lon >> 8) as u8;
+        self[7] = lon as u8;
+        self[8] = flags;
+        self[9] = hdop;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_NO_FIX, GGA_WITH_FIX, shift_buffer};
+    use crate::{extract_gga, extract_gga_into};
+
+    /// Position packed into a payload after an application header byte, longitude first.
+    struct Payload {
+        bytes: [u8; 11],
+    }
+
+    impl PositionEncoder for Payload {
+        fn encode(&mut self, lat: u32, lon: u32, flags: u8, hdop: u8) {
+            self.bytes[1..5].copy_from_slice(&lon.to_le_bytes());
+            self.bytes[5..9].copy_from_slice(&lat.to_le_bytes());
+            self.bytes[9] = flags;
+            self.bytes[10] = hdop;
+        }
+    }
+
+    #[test]
+    fn test_extract_gga_into() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut position_block = [0; 10];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            assert!(extract_gga(&buffer, i, &mut position_block));
+            let mut payload = Payload { bytes: [0xA5; 11] };
+            assert!(extract_gga_into(&buffer, i, &mut payload));
+            assert_eq!(payload.bytes[0], 0xA5);
+            assert_eq!(payload.bytes[1..5], [63, 169, 54, 91]);
+            assert_eq!(payload.bytes[5..9], [210, 225, 248, 162]);
+            assert_eq!(payload.bytes[9..11], position_block[8..10]);
+
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            let mut payload = Payload { bytes: [0xA5; 11] };
+            assert!(!extract_gga_into(&buffer, i, &mut payload));
+            assert_eq!(payload.bytes, [0xA5; 11]);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! Versioned 16-byte position block with time, altitude, satellites and fix quality.
+use crate::buffer::NmeaBuffer;
+use crate::decode::DecodedFix;
+use crate::degrees::total_minutes_at;
+use crate::extract_gga_ext;
+
+/// Layout version stored in the upper nibble of byte 8 of the extended block.
+pub const EXTENDED_BLOCK_VERSION: u8 = 1;
+
+/// Range of the signed 21-bit altitude field in decimetres.
+const ALTITUDE_MIN: i32 = -(1 << 20);
+const ALTITUDE_MAX: i32 = (1 << 20) - 1;
+
+/// Fix decoded from the extended block filled by [`extract_gga_extended`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "pyo3", pyo3::pyclass(get_all, frozen, from_py_object))]
+pub struct ExtendedFix {
+    /// Position and HDOP.
+    pub position: DecodedFix,
+    /// UTC time of the fix in whole seconds since midnight.
+    pub time: u32,
+    /// Altitude above mean sea level in decimetres.
+    pub altitude: i32,
+    /// Number of satellites used in the fix, saturating at 63.
+    pub satellites: u8,
+    /// GGA fix quality indicator.
+    pub quality: u8,
+}
+
+/// Extract position data, time, altitude, satellites and fix quality from a GGA sentence into one 16-byte block.
+///
+/// The extended block layout, version 1, is:
+/// * `[0..4]` - Latitude in minutes scaled by 10^5, big-endian. Unlike [`extract_gga`](crate::extract_gga), it does
+///   not wrap.
+/// * `[4..8]` - Longitude in minutes scaled by 10^5, big-endian.
+/// * `[8]` - Bits 7..4 are the layout version, [`EXTENDED_BLOCK_VERSION`]. Bit 1 is set if north, bit 0 if east.
+/// * `[9]` - HDOP in tenths, saturating at 255.
+/// * `[10..16]` - A 48-bit big-endian word:
+///   * Bits 47..31 - UTC time of the fix in whole seconds since midnight.
+///   * Bits 30..27 - Fix quality.
+///   * Bits 26..21 - Number of satellites used in the fix, saturating at 63.
+///   * Bits 20..0 - Altitude above mean sea level in decimetres, signed, clamped to ±104 km.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `extended_block` - Output buffer where parsed data will be stored (16 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix.
+#[inline]
+pub fn extract_gga_extended<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    extended_block: &mut [u8; 16],
+) -> bool {
+    let mut position_block = [0; 10];
+    let mut extension_block = [0; 16];
+    if !extract_gga_ext(buffer, sentence_begin, &mut position_block, &mut extension_block) {
+        return false;
+    }
+    let (lat_minutes, lon_minutes) = total_minutes_at(buffer, sentence_begin);
+    extended_block[..4].copy_from_slice(&lat_minutes.to_be_bytes());
+    extended_block[4..8].copy_from_slice(&lon_minutes.to_be_bytes());
+    extended_block[8] = EXTENDED_BLOCK_VERSION << 4 | position_block[8];
+    extended_block[9] = position_block[9];
+
+    let altitude = i32::from_be_bytes([
+        extension_block[0],
+        extension_block[1],
+        extension_block[2],
+        extension_block[3],
+    ])
+    .clamp(ALTITUDE_MIN, ALTITUDE_MAX);
+    let quality = extension_block[6] as u64 & 0xF;
+    let satellites = if extension_block[7] < 64 {
+        extension_block[7]
+    } else {
+        63
+    } as u64;
+    let time = u32::from_be_bytes([
+        extension_block[12],
+        extension_block[13],
+        extension_block[14],
+        extension_block[15],
+    ]) / 1000;
+
+    let word = (time as u64) << 31 | quality << 27 | satellites << 21 | (altitude as u64 & 0x1F_FFFF);
+    extended_block[10] = (word >> 40) as u8;
+    extended_block[11] = (word >> 32) as u8;
+    extended_block[12] = (word >> 24) as u8;
+    extended_block[13] = (word >> 16) as u8;
+    extended_block[14] = (word >> 8) as u8;
+    extended_block[15] = word as u8;
+
+    true
+}
+
+/// Decode an extended block filled by [`extract_gga_extended`].
+///
+/// ### Returns
+/// The fix, or `None` if the block is not layout version [`EXTENDED_BLOCK_VERSION`].
+#[inline]
+pub const fn decode_extended_block(extended_block: &[u8; 16]) -> Option<ExtendedFix> {
+    if extended_block[8] >> 4 != EXTENDED_BLOCK_VERSION {
+        return None;
+    }
+    let word = u64::from_be_bytes([
+        0,
+        0,
+        extended_block[10],
+        extended_block[11],
+        extended_block[12],
+        extended_block[13],
+        extended_block[14],
+        extended_block[15],
+    ]);
+    Some(ExtendedFix {
+        position: DecodedFix {
+            lat_minutes: u32::from_be_bytes([
+                extended_block[0],
+                extended_block[1],
+                extended_block[2],
+                extended_block[3],
+            ]),
+            lon_minutes: u32::from_be_bytes([
+                extended_block[4],
+                extended_block[5],
+                extended_block[6],
+                extended_block[7],
+            ]),
+            north: extended_block[8] & 0b10 != 0,
+            east: extended_block[8] & 0b01 != 0,
+            hdop: extended_block[9],
+        },
+        time: (word >> 31) as u32,
+        // Sign extend from 21 bits
+        altitude: ((word as u32) << 11) as i32 >> 11,
+        satellites: (word >> 21) as u8 & 0x3F,
+        quality: (word >> 27) as u8 & 0xF,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::degrees::extract_gga_e7;
+    use crate::extract_gga;
+    use crate::tests::{GGA_HIGH_LATITUDE, GGA_NO_FIX, shift_buffer};
+
+    #[test]
+    fn test_extended_no_fix() {
+        let mut extended_block = [0; 16];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert!(!extract_gga_extended(&buffer, i, &mut extended_block));
+            assert_eq!(extended_block, [0; 16]);
+        }
+    }
+
+    #[test]
+    fn test_extended_with_fix() {
+        let mut extended_block = [0; 16];
+        let mut position_block = [0; 10];
+        let mut e7_block = [0; 9];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, time, altitude, satellites, quality) in GGA_WITH_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(extract_gga_extended(&buffer, i, &mut extended_block));
+                assert!(extract_gga(&buffer, i, &mut position_block));
+                assert!(extract_gga_e7(&buffer, i, &mut e7_block));
+                assert_eq!(extended_block[8], 0x10 | position_block[8]);
+                assert_eq!(extended_block[9], position_block[9]);
+                let fix = decode_extended_block(&extended_block).unwrap();
+                assert_eq!(fix.position.lat_e7().to_be_bytes(), e7_block[..4]);
+                assert_eq!(fix.position.lon_e7().to_be_bytes(), e7_block[4..8]);
+                assert_eq!(
+                    (fix.time, fix.altitude, fix.satellites, fix.quality),
+                    (*time, *altitude, *satellites, *quality)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_extended_high_latitude() {
+        let mut extended_block = [0; 16];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_HIGH_LATITUDE, i);
+            assert!(extract_gga_extended(&buffer, i, &mut extended_block));
+            let position = decode_extended_block(&extended_block).unwrap().position;
+            assert_eq!(position.lat_minutes, 309_012_345);
+            assert_eq!(position.lat_e7(), 515020575);
+            assert_eq!(position.lon_e7(), -1275720);
+        }
+    }
+
+    #[test]
+    fn test_decode_extended_version() {
+        let mut extended_block = [0; 16];
+        assert_eq!(decode_extended_block(&extended_block), None);
+        extended_block[8] = 0x20;
+        assert_eq!(decode_extended_block(&extended_block), None);
+        extended_block[8] = 0x13;
+        let fix = decode_extended_block(&extended_block).unwrap();
+        assert!(fix.position.north);
+        assert!(fix.position.east);
+    }
+
+    const GGA_WITH_FIX: [(&[u8], u32, i32, u8, u8); 6] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            18720,
+            1034,
+            7,
+            1,
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            65701,
+            -123,
+            3,
+            2,
+        ),
+        (
+            b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,4,12,39.9,12345.6,M,41.1,M,1.0,1023*55\r\n",
+            65701,
+            123456,
+            12,
+            4,
+        ),
+        (
+            b"$GNGGA,235959.999,3615.12012,S,06357.25158,W,5,35,0.6,0.0,M,0.0,M,,*7C\r\n",
+            86399,
+            0,
+            35,
+            5,
+        ),
+        // Altitude clamped to the 21-bit field
+        (
+            b"$GNGGA,235959.999,3615.12012,S,06357.25158,W,8,99,0.6,-123456.7,M,0.0,M,,*6A\r\n",
+            86399,
+            -1048576,
+            63,
+            8,
+        ),
+        // Above the 42.95° that extract_gga wraps at
+        (
+            b"$GNGGA,120000.000,5130.12345,N,00007.65432,W,1,09,0.9,35.0,M,47.0,M,,*4A\r\n",
+            43200,
+            350,
+            9,
+            1,
+        ),
+    ];
+}
@@ -0,0 +1,114 @@
+//! C interface for linking the parser into C firmware. Header generation with cbindgen picks up these exports.
+use crate::fix::{GgaFix, extract_gga_struct};
+use crate::is_gga;
+
+/// View a C buffer as a slice, or `None` if it is null or empty.
+///
+/// ### Safety
+/// `buffer` must be null or valid for reads of `buffer_len` bytes.
+#[inline(always)]
+unsafe fn buffer_slice<'a>(buffer: *const u8, buffer_len: usize) -> Option<&'a [u8]> {
+    if buffer.is_null() || buffer_len == 0 {
+        return None;
+    }
+    // SAFETY: Non-null and valid for `buffer_len` bytes per the caller's contract
+    Some(unsafe { core::slice::from_raw_parts(buffer, buffer_len) })
+}
+
+/// Determine if the sentence is a GGA sentence, see [`is_gga`].
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `buffer_len` - Length of the buffer in bytes.
+/// * `sentence_begin` - Starting index of the sentence in the buffer.
+///
+/// ### Returns
+/// If the sentence is a GGA sentence. False if `buffer` is null or empty.
+///
+/// ### Safety
+/// `buffer` must be null or valid for reads of `buffer_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gga_is_gga(buffer: *const u8, buffer_len: usize, sentence_begin: usize) -> bool {
+    // SAFETY: Forwarded from the caller
+    match unsafe { buffer_slice(buffer, buffer_len) } {
+        Some(buffer) => is_gga(buffer, sentence_begin),
+        None => false,
+    }
+}
+
+/// Extract position data from a GGA sentence in a circular buffer, see [`extract_gga_struct`].
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `buffer_len` - Length of the buffer in bytes.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `fix` - Output where the fix will be stored.
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix. `fix` is left untouched otherwise, or if any pointer is null.
+///
+/// ### Safety
+/// `buffer` must be null or valid for reads of `buffer_len` bytes, and `fix` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gga_extract(
+    buffer: *const u8,
+    buffer_len: usize,
+    sentence_begin: usize,
+    fix: *mut GgaFix,
+) -> bool {
+    if fix.is_null() {
+        return false;
+    }
+    // SAFETY: Forwarded from the caller
+    let Some(buffer) = (unsafe { buffer_slice(buffer, buffer_len) }) else {
+        return false;
+    };
+    match extract_gga_struct(buffer, sentence_begin) {
+        Some(extracted) => {
+            // SAFETY: Non-null and valid for writes per the caller's contract
+            unsafe { fix.write(extracted) };
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_NO_FIX, GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_gga_extract() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut fix = GgaFix::default();
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            let expected_fix = extract_gga_struct(&buffer, i).unwrap();
+            unsafe {
+                assert!(gga_is_gga(buffer.as_ptr(), buffer.len(), i));
+                assert!(gga_extract(buffer.as_ptr(), buffer.len(), i, &mut fix));
+            }
+            assert_eq!(fix, expected_fix);
+
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            unsafe {
+                assert!(gga_is_gga(buffer.as_ptr(), buffer.len(), i));
+                assert!(!gga_extract(buffer.as_ptr(), buffer.len(), i, &mut fix));
+            }
+            assert_eq!(fix, expected_fix);
+        }
+    }
+
+    #[test]
+    fn test_null() {
+        let buffer: [u8; 1024] = [0; 1024];
+        let mut fix = GgaFix::default();
+        unsafe {
+            assert!(!gga_is_gga(core::ptr::null(), 1024, 0));
+            assert!(!gga_is_gga(buffer.as_ptr(), 0, 0));
+            assert!(!gga_extract(core::ptr::null(), 1024, 0, &mut fix));
+            assert!(!gga_extract(buffer.as_ptr(), 1024, 0, core::ptr::null_mut()));
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Generic access to the comma separated fields of any sentence.
+use crate::buffer::NmeaBuffer;
+use crate::parse::MAX_SENTENCE_LENGTH;
+use core::ops::Range;
+
+/// Location of a field in a circular buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FieldRef {
+    /// Index of the first byte of the field in the buffer.
+    pub begin: usize,
+    /// Length of the field in bytes, excluding delimiters. May extend past the end of the buffer.
+    pub length: usize,
+}
+
+impl FieldRef {
+    /// If the field has no content.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The field as ranges of a buffer of `buffer_len` bytes. The second range is empty unless the field wraps.
+    #[inline]
+    pub const fn ranges(&self, buffer_len: usize) -> (Range<usize>, Range<usize>) {
+        let end = self.begin + self.length;
+        if end <= buffer_len {
+            (self.begin..end, 0..0)
+        } else {
+            (self.begin..buffer_len, 0..end - buffer_len)
+        }
+    }
+
+    /// Copy the field out of the circular buffer.
+    ///
+    /// ### Returns
+    /// The number of bytes copied, truncated to the length of `out`.
+    #[inline]
+    pub fn copy_to<B: NmeaBuffer + ?Sized>(&self, buffer: &B, out: &mut [u8]) -> usize {
+        let length = self.length.min(out.len());
+        for (i, b) in out[..length].iter_mut().enumerate() {
+            *b = buffer.at(self.begin + i);
+        }
+        length
+    }
+}
+
+/// Iterator over the comma separated fields of a sentence, yielding each as a pair of buffer ranges.
+///
+/// Field 0 is the address field, e.g. `GNGGA`, and the data fields follow from 1. The last field ends at the `*`
+/// checksum delimiter, the line ending, or the end of the sentence. The second range of a field is empty unless the
+/// field wraps past the end of the buffer. An empty buffer has no fields.
+#[derive(Clone, Debug)]
+pub struct Fields<'a, B: NmeaBuffer + ?Sized> {
+    buffer: &'a B,
+    index: usize,
+    end: usize,
+    done: bool,
+}
+
+impl<'a, B: NmeaBuffer + ?Sized> Fields<'a, B> {
+    /// Iterate over the fields of a sentence.
+    ///
+    /// ### Arguments
+    /// * `buffer` - A circular buffer containing NMEA 0183 data.
+    /// * `sentence_begin` - Starting index of the sentence in the buffer.
+    /// * `sentence_len` - Length of the sentence, as from
+    ///   [`calculate_sentence_length`](crate::calculate_sentence_length).
+    #[inline]
+    pub fn new(buffer: &'a B, sentence_begin: usize, sentence_len: usize) -> Fields<'a, B> {
+        Fields {
+            buffer,
+            index: sentence_begin + 1,
+            end: sentence_begin + sentence_len,
+            // Field locations are wrapped to the buffer length, which an empty buffer cannot do
+            done: buffer.is_empty(),
+        }
+    }
+
+    /// Locate the next field.
+    #[inline]
+    pub fn next_field(&mut self) -> Option<FieldRef> {
+        if self.done {
+            return None;
+        }
+        let field_begin = self.index;
+        while self.index < self.end {
+            match self.buffer.at(self.index) {
+                b',' => {
+                    let field = FieldRef {
+                        begin: field_begin % self.buffer.len(),
+                        length: self.index - field_begin,
+                    };
+                    self.index += 1;
+                    return Some(field);
+                }
+                b'*' | b'\r' | b'\n' => break,
+                _ => self.index += 1,
+            }
+        }
+        self.done = true;
+        Some(FieldRef {
+            begin: field_begin % self.buffer.len(),
+            length: self.index - field_begin,
+        })
+    }
+}
+
+impl<B: NmeaBuffer + ?Sized> Iterator for Fields<'_, B> {
+    type Item = (Range<usize>, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(Range<usize>, Range<usize>)> {
+        let field = self.next_field()?;
+        Some(field.ranges(self.buffer.len()))
+    }
+}
+
+/// Call `visitor` for each comma separated field of a sentence.
+///
+/// Fields are numbered as by [`Fields`].
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the sentence in the buffer.
+/// * `sentence_len` - Length of the sentence, as from [`calculate_sentence_length`](crate::calculate_sentence_length).
+/// * `visitor` - Called with the index and location of each field.
+#[inline]
+pub fn extract_fields<B: NmeaBuffer + ?Sized, F: FnMut(usize, FieldRef)>(
+    buffer: &B,
+    sentence_begin: usize,
+    sentence_len: usize,
+    mut visitor: F,
+) {
+    let mut fields = Fields::new(buffer, sentence_begin, sentence_len);
+    let mut field_index = 0;
+    while let Some(field) = fields.next_field() {
+        visitor(field_index, field);
+        field_index += 1;
+    }
+}
+
+/// Locate the `n`-th field of a sentence, numbered as by [`Fields`].
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the sentence in the buffer.
+/// * `n` - Index of the field, e.g. 6 for the GGA fix quality.
+///
+/// ### Returns
+/// The offset of the field from `sentence_begin` and its length, if the sentence has that many fields.
+#[inline]
+pub fn field<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, n: usize) -> Option<(usize, usize)> {
+    let mut fields = Fields::new(buffer, sentence_begin, MAX_SENTENCE_LENGTH);
+    for _ in 0..n {
+        fields.next_field()?;
+    }
+    let field = fields.next_field()?;
+    let len = buffer.len();
+    Some(((field.begin + len - sentence_begin % len) % len, field.length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_extract_fields() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            let mut count = 0;
+            extract_fields(&buffer, i, GGA_WITH_FIX.len(), |field_index, field| {
+                assert_eq!(field_index, count);
+                let mut out = [0; 16];
+                let length = field.copy_to(&buffer, &mut out);
+                assert_eq!(&out[..length], GGA_FIELDS[field_index]);
+                assert_eq!(field.is_empty(), GGA_FIELDS[field_index].is_empty());
+                count += 1;
+            });
+            assert_eq!(count, GGA_FIELDS.len());
+        }
+    }
+
+    #[test]
+    fn test_extract_fields_without_checksum() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, b"$PMTK001,604,3", 1020);
+        let mut fields = [FieldRef::default(); 3];
+        extract_fields(&buffer, 1020, 14, |field_index, field| fields[field_index] = field);
+        assert_eq!(
+            fields,
+            [
+                FieldRef { begin: 1021, length: 7 },
+                FieldRef { begin: 5, length: 3 },
+                FieldRef { begin: 9, length: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fields() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            let mut count = 0;
+            for ((first, second), expected_field) in Fields::new(&buffer, i, GGA_WITH_FIX.len()).zip(GGA_FIELDS.iter())
+            {
+                assert_eq!(first.len() + second.len(), expected_field.len());
+                assert!(buffer[first.clone()].iter().chain(buffer[second].iter()).eq(expected_field.iter()));
+                count += 1;
+            }
+            assert_eq!(count, GGA_FIELDS.len());
+            assert_eq!(Fields::new(&buffer, i, GGA_WITH_FIX.len()).count(), GGA_FIELDS.len());
+        }
+    }
+
+    #[test]
+    fn test_field() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            assert_eq!(field(&buffer, i, 0), Some((1, 5)));
+            assert_eq!(field(&buffer, i, 2), Some((18, 10)));
+            // Fix quality and number of satellites
+            assert_eq!(field(&buffer, i, 6), Some((45, 1)));
+            assert_eq!(field(&buffer, i, 7), Some((47, 2)));
+            assert_eq!(field(&buffer, i, 14), Some((70, 0)));
+            assert_eq!(field(&buffer, i, 15), None);
+        }
+    }
+
+    #[test]
+    fn test_empty_buffer() {
+        assert_eq!(Fields::new(&[][..], 0, GGA_WITH_FIX.len()).count(), 0);
+        extract_fields(&[][..], 0, GGA_WITH_FIX.len(), |_, _| unreachable!());
+        assert_eq!(field(&[][..], 0, 0), None);
+    }
+
+    #[test]
+    fn test_field_ranges() {
+        let field = FieldRef { begin: 1020, length: 6 };
+        assert_eq!(field.ranges(1024), (1020..1024, 0..2));
+        let field = FieldRef { begin: 1018, length: 6 };
+        assert_eq!(field.ranges(1024), (1018..1024, 0..0));
+    }
+
+    const GGA_FIELDS: [&[u8]; 15] = [
+        b"GNGGA",
+        b"051200.993",
+        b"2734.21973",
+        b"S",
+        b"15303.08927",
+        b"E",
+        b"1",
+        b"07",
+        b"2.8",
+        b"103.4",
+        b"M",
+        b"41.1",
+        b"M",
+        b"",
+        b"",
+    ];
+}
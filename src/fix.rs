@@ -0,0 +1,514 @@
+//! Typed representations of GGA fix data.
+use crate::buffer::NmeaBuffer;
+#[cfg(feature = "csv")]
+use crate::degrees::minutes_e5_to_degrees_e7;
+use crate::degrees::{total_minutes, total_minutes_at};
+use crate::extract_gga;
+use crate::parse::byte_at;
+
+/// Classification of the GGA fix quality indicator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum FixType {
+    NoFix = 0,
+    Gps = 1,
+    Dgps = 2,
+    Pps = 3,
+    RtkFixed = 4,
+    RtkFloat = 5,
+    DeadReckoning = 6,
+    Manual = 7,
+    Simulation = 8,
+}
+
+impl FixType {
+    /// Classify a GGA fix quality value. Unknown values are treated as no fix.
+    #[inline]
+    pub const fn from_quality(quality: u8) -> FixType {
+        match quality {
+            1 => FixType::Gps,
+            2 => FixType::Dgps,
+            3 => FixType::Pps,
+            4 => FixType::RtkFixed,
+            5 => FixType::RtkFloat,
+            6 => FixType::DeadReckoning,
+            7 => FixType::Manual,
+            8 => FixType::Simulation,
+            _ => FixType::NoFix,
+        }
+    }
+
+    /// Bit of this fix type in a quality mask for [`extract_gga_if`].
+    #[inline]
+    pub const fn mask(self) -> u16 {
+        1 << self as u8
+    }
+
+    /// If the fix is RTK fixed or RTK float.
+    #[inline]
+    pub const fn is_rtk(self) -> bool {
+        matches!(self, FixType::RtkFixed | FixType::RtkFloat)
+    }
+}
+
+/// Classify the fix quality of a GGA sentence without extracting the position.
+///
+/// Relies on the same fixed-offset layout as [`extract_gga`](crate::extract_gga), so sentences without a time or
+/// latitude field should be rejected first. The quality is also available from
+/// [`extract_gga_ext`](crate::extract_gga_ext) through [`FixType::from_quality`].
+#[inline]
+pub fn gga_fix_type<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> FixType {
+    let quality = byte_at(buffer, sentence_begin + 45);
+    if quality.is_ascii_digit() {
+        FixType::from_quality(quality - b'0')
+    } else {
+        FixType::NoFix
+    }
+}
+
+/// Extract position data from a GGA sentence only if its fix quality is accepted.
+///
+/// The quality is read before the position, so sentences that would be discarded are not parsed.
+///
+/// ### Arguments
+/// * `quality_mask` - Accepted fix types, combined from [`FixType::mask`], e.g.
+///   `FixType::RtkFixed.mask() | FixType::RtkFloat.mask()`.
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix of an accepted type. The position block is left untouched otherwise.
+#[inline]
+pub fn extract_gga_if<B: NmeaBuffer + ?Sized>(
+    quality_mask: u16,
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 10],
+) -> bool {
+    // The quality is only at a fixed offset if the time and latitude fields are present
+    if byte_at(buffer, sentence_begin + 7) == b',' || byte_at(buffer, sentence_begin + 18) == b',' {
+        return false;
+    }
+    if gga_fix_type(buffer, sentence_begin).mask() & quality_mask == 0 {
+        return false;
+    }
+    extract_gga(buffer, sentence_begin, position_block)
+}
+
+/// Position data extracted from a GGA sentence.
+///
+/// `lat`, `lon`, `flags` and `hdop` hold the same values as the position block of [`extract_gga`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct GgaFix {
+    /// Latitude as `ddmm.mmmmm` scaled by 10^6. Wraps from about 42.95°, as the position block does.
+    pub lat: u32,
+    /// Latitude in minutes scaled by 10^5, e.g. 27°34.21973' is 165421973. Does not wrap.
+    pub lat_total_minutes: u32,
+    /// Longitude as `dddmm.mmmmm` scaled by 10^5.
+    pub lon: u32,
+    /// Bit 1 is set if north, bit 0 if east.
+    pub flags: u8,
+    /// HDOP in tenths, saturating at 255.
+    pub hdop: u8,
+    /// GGA fix quality indicator.
+    pub quality: u8,
+}
+
+impl GgaFix {
+    /// Build a fix from a position block filled by [`extract_gga`] and a fix quality.
+    ///
+    /// The block's latitude wraps from about 42.95°, so `lat_total_minutes` does too. Prefer [`extract_gga_struct`]
+    /// where the sentence is still available.
+    #[inline]
+    pub const fn from_position_block(position_block: &[u8; 10], quality: u8) -> GgaFix {
+        let lat = u32::from_be_bytes([
+            position_block[0],
+            position_block[1],
+            position_block[2],
+            position_block[3],
+        ]);
+        GgaFix {
+            lat,
+            lat_total_minutes: total_minutes(lat / 10),
+            lon: u32::from_be_bytes([
+                position_block[4],
+                position_block[5],
+                position_block[6],
+                position_block[7],
+            ]),
+            flags: position_block[8],
+            hdop: position_block[9],
+            quality,
+        }
+    }
+
+    /// Pack the fix into the position block layout of [`extract_gga`].
+    #[inline]
+    pub const fn to_position_block(&self) -> [u8; 10] {
+        let lat = self.lat.to_be_bytes();
+        let lon = self.lon.to_be_bytes();
+        [
+            lat[0], lat[1], lat[2], lat[3], lon[0], lon[1], lon[2], lon[3], self.flags, self.hdop,
+        ]
+    }
+
+    /// If the latitude is in the northern hemisphere.
+    #[inline]
+    pub const fn is_north(&self) -> bool {
+        self.flags & 0b10 != 0
+    }
+
+    /// If the longitude is in the eastern hemisphere.
+    #[inline]
+    pub const fn is_east(&self) -> bool {
+        self.flags & 0b01 != 0
+    }
+
+    /// Whole degrees of latitude.
+    #[inline]
+    pub const fn lat_degrees(&self) -> u32 {
+        self.lat_total_minutes / 6_000_000
+    }
+
+    /// Minutes of latitude scaled by 10^6.
+    #[inline]
+    pub const fn lat_minutes(&self) -> u32 {
+        self.lat_total_minutes % 6_000_000 * 10
+    }
+
+    /// Whole degrees of longitude.
+    #[inline]
+    pub const fn lon_degrees(&self) -> u32 {
+        self.lon / 10_000_000
+    }
+
+    /// Minutes of longitude scaled by 10^5.
+    #[inline]
+    pub const fn lon_minutes(&self) -> u32 {
+        self.lon % 10_000_000
+    }
+
+    /// Classification of the fix quality.
+    #[inline]
+    pub const fn fix_type(&self) -> FixType {
+        FixType::from_quality(self.quality)
+    }
+
+    /// Latitude in degrees, negative if south.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn lat_deg(&self) -> f64 {
+        let lat = self.lat_total_minutes as f64 / 6_000_000.0;
+        if self.is_north() { lat } else { -lat }
+    }
+
+    /// Longitude in degrees, negative if west.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn lon_deg(&self) -> f64 {
+        let lon = self.lon_degrees() as f64 + self.lon_minutes() as f64 / 6_000_000.0;
+        if self.is_east() { lon } else { -lon }
+    }
+
+    /// Latitude in degrees as `f32`, negative if south. Resolution is about a metre.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn lat_deg_f32(&self) -> f32 {
+        self.lat_deg() as f32
+    }
+
+    /// Longitude in degrees as `f32`, negative if west. Resolution is about a metre.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn lon_deg_f32(&self) -> f32 {
+        self.lon_deg() as f32
+    }
+
+    /// HDOP as `f32`.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn hdop_f32(&self) -> f32 {
+        self.hdop as f32 / 10.0
+    }
+
+    /// Write the fix as a CSV line, `lat,lon,hdop,quality` followed by `\n`.
+    ///
+    /// Latitude and longitude are signed decimal degrees with 7 decimals, e.g.
+    /// `-27.5703288,153.0514878,2.8,1`.
+    #[cfg(feature = "csv")]
+    pub fn write_csv<W: core::fmt::Write + ?Sized>(&self, out: &mut W) -> core::fmt::Result {
+        let lat = minutes_e5_to_degrees_e7(self.lat_total_minutes);
+        let lon = minutes_e5_to_degrees_e7(self.lon_degrees() * 6_000_000 + self.lon_minutes());
+        writeln!(
+            out,
+            "{}{}.{:07},{}{}.{:07},{}.{},{}",
+            if self.is_north() { "" } else { "-" },
+            lat / 10_000_000,
+            lat % 10_000_000,
+            if self.is_east() { "" } else { "-" },
+            lon / 10_000_000,
+            lon % 10_000_000,
+            self.hdop / 10,
+            self.hdop % 10,
+            self.quality,
+        )
+    }
+}
+
+/// Formats as e.g. `27°34.21973'S 153°03.08927'E hdop 2.8 quality 1`.
+#[cfg(feature = "defmt")]
+impl defmt::Format for GgaFix {
+    fn format(&self, f: defmt::Formatter) {
+        // From the total minutes rather than `lat`, which wraps
+        let lat_minutes = self.lat_total_minutes % 6_000_000;
+        let lon_minutes = self.lon_minutes();
+        defmt::write!(
+            f,
+            "{=u32}°{=u32:02}.{=u32:05}'{=char} {=u32}°{=u32:02}.{=u32:05}'{=char} hdop {=u8}.{=u8} quality {=u8}",
+            self.lat_total_minutes / 6_000_000,
+            lat_minutes / 100_000,
+            lat_minutes % 100_000,
+            if self.is_north() { 'N' } else { 'S' },
+            self.lon_degrees(),
+            lon_minutes / 100_000,
+            lon_minutes % 100_000,
+            if self.is_east() { 'E' } else { 'W' },
+            self.hdop / 10,
+            self.hdop % 10,
+            self.quality,
+        )
+    }
+}
+
+/// Extract position data from a GGA sentence in a circular buffer into a [`GgaFix`].
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+///
+/// ### Returns
+/// The fix if the sentence contains one.
+#[inline]
+pub fn extract_gga_struct<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> Option<GgaFix> {
+    let mut position_block = [0; 10];
+    if !extract_gga(buffer, sentence_begin, &mut position_block) {
+        return None;
+    }
+    let (lat_total_minutes, _) = total_minutes_at(buffer, sentence_begin);
+    Some(GgaFix {
+        lat_total_minutes,
+        ..GgaFix::from_position_block(&position_block, gga_fix_type(buffer, sentence_begin) as u8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_HIGH_LATITUDE, shift_buffer};
+
+    #[test]
+    fn test_from_quality() {
+        for quality in 0..=8 {
+            assert_eq!(FixType::from_quality(quality) as u8, quality);
+        }
+        assert_eq!(FixType::from_quality(9), FixType::NoFix);
+        assert!(FixType::RtkFixed.is_rtk());
+        assert!(FixType::RtkFloat.is_rtk());
+        assert!(!FixType::Dgps.is_rtk());
+    }
+
+    #[test]
+    fn test_gga_fix_type() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_fix_type) in GGA_FIX_TYPE.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(gga_fix_type(&buffer, i), *expected_fix_type);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_gga_if() {
+        let rtk_mask = FixType::RtkFixed.mask() | FixType::RtkFloat.mask();
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n", i);
+            let mut position_block = [0; 10];
+            assert!(!extract_gga_if(FixType::NoFix.mask(), &buffer, i, &mut position_block));
+            assert_eq!(position_block, [0; 10]);
+        }
+        for (sentence, fix_type) in GGA_FIX_TYPE.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let mut expected_position_block = [0; 10];
+                assert!(extract_gga(&buffer, i, &mut expected_position_block));
+                let mut position_block = [0; 10];
+                assert_eq!(
+                    extract_gga_if(rtk_mask, &buffer, i, &mut position_block),
+                    fix_type.is_rtk()
+                );
+                if fix_type.is_rtk() {
+                    assert_eq!(position_block, expected_position_block);
+                } else {
+                    assert_eq!(position_block, [0; 10]);
+                }
+                assert!(extract_gga_if(fix_type.mask(), &buffer, i, &mut position_block));
+                assert_eq!(position_block, expected_position_block);
+                assert!(!extract_gga_if(!fix_type.mask(), &buffer, i, &mut position_block));
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_gga_struct() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n", i);
+            assert_eq!(extract_gga_struct(&buffer, i), None);
+        }
+        for (sentence, expected_fix_type) in GGA_FIX_TYPE.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let mut position_block = [0; 10];
+                assert!(extract_gga(&buffer, i, &mut position_block));
+                let fix = extract_gga_struct(&buffer, i).unwrap();
+                assert_eq!(fix.to_position_block(), position_block);
+                assert_eq!(fix.fix_type(), *expected_fix_type);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gga_fix_accessors() {
+        let fix = GgaFix::from_position_block(&[162, 248, 225, 210, 91, 54, 169, 63, 1, 28], 1);
+        assert_eq!(fix.lat, 2734219730);
+        assert_eq!(fix.lon, 1530308927);
+        assert_eq!(fix.lat_degrees(), 27);
+        assert_eq!(fix.lat_minutes(), 34219730);
+        assert_eq!(fix.lon_degrees(), 153);
+        assert_eq!(fix.lon_minutes(), 308927);
+        assert!(!fix.is_north());
+        assert!(fix.is_east());
+        assert_eq!(fix.hdop, 28);
+        assert_eq!(fix.fix_type(), FixType::Gps);
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_format() {
+        fn assert_format<T: defmt::Format>() {}
+        assert_format::<GgaFix>();
+        assert_format::<FixType>();
+        assert_format::<crate::GgaError>();
+        assert_format::<crate::DecodedFix>();
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_write_csv() {
+        /// Fixed capacity line buffer, as used for SD card logging without an allocator.
+        struct Line {
+            bytes: [u8; 64],
+            length: usize,
+        }
+
+        impl core::fmt::Write for Line {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.length + s.len();
+                self.bytes.get_mut(self.length..end).ok_or(core::fmt::Error)?.copy_from_slice(s.as_bytes());
+                self.length = end;
+                Ok(())
+            }
+        }
+
+        for (position_block, quality, expected_line) in CSV.iter() {
+            let fix = GgaFix::from_position_block(position_block, *quality);
+            let mut line = Line {
+                bytes: [0; 64],
+                length: 0,
+            };
+            fix.write_csv(&mut line).unwrap();
+            assert_eq!(&line.bytes[..line.length], *expected_line);
+        }
+
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_HIGH_LATITUDE, 0);
+        let mut line = Line {
+            bytes: [0; 64],
+            length: 0,
+        };
+        extract_gga_struct(&buffer, 0).unwrap().write_csv(&mut line).unwrap();
+        assert_eq!(&line.bytes[..line.length], b"51.5020575,-0.1275720,0.9,1\n");
+    }
+
+    #[cfg(feature = "csv")]
+    const CSV: [([u8; 10], u8, &[u8]); 3] = [
+        (
+            [162, 248, 225, 210, 91, 54, 169, 63, 1, 28],
+            1,
+            b"-27.5703288,153.0514878,2.8,1\n",
+        ),
+        (
+            [235, 28, 78, 124, 62, 87, 107, 238, 2, 21],
+            2,
+            b"39.7416810,-104.9861090,2.1,2\n",
+        ),
+        (
+            [0, 0, 0, 0, 2, 188, 161, 238, 3, 255],
+            4,
+            b"0.0000000,4.9861090,25.5,4\n",
+        ),
+    ];
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_gga_fix_float() {
+        let fix = GgaFix::from_position_block(&[162, 248, 225, 210, 91, 54, 169, 63, 1, 28], 1);
+        assert!((fix.lat_deg() - -27.570328833).abs() < 1e-9);
+        assert!((fix.lon_deg() - 153.051487833).abs() < 1e-9);
+        assert!((fix.lat_deg_f32() - -27.570328).abs() < 1e-5);
+        assert!((fix.lon_deg_f32() - 153.05149).abs() < 1e-4);
+        assert_eq!(fix.hdop_f32(), 2.8);
+    }
+
+    #[test]
+    fn test_extract_gga_struct_high_latitude() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_HIGH_LATITUDE, i);
+            let fix = extract_gga_struct(&buffer, i).unwrap();
+            assert_eq!(fix.lat_total_minutes, 309012345);
+            assert_eq!(fix.lat_degrees(), 51);
+            assert_eq!(fix.lat_minutes(), 30123450);
+            assert!(fix.is_north());
+            #[cfg(feature = "float")]
+            {
+                assert!((fix.lat_deg() - 51.502057500).abs() < 1e-9);
+                assert!((fix.lat_deg_f32() - 51.502058).abs() < 1e-5);
+            }
+        }
+    }
+
+    const GGA_FIX_TYPE: [(&[u8], FixType); 4] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            FixType::Gps,
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            FixType::Dgps,
+        ),
+        (
+            b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,4,12,39.9,12345.6,M,41.1,M,1.0,1023*55\r\n",
+            FixType::RtkFixed,
+        ),
+        (
+            b"$GNGGA,235959.999,3615.12012,S,06357.25158,W,5,35,0.6,0.0,M,0.0,M,,*7C\r\n",
+            FixType::RtkFloat,
+        ),
+    ];
+}
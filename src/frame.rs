@@ -0,0 +1,355 @@
+//! Locate complete sentences in a window of a DMA circular buffer.
+use crate::buffer::NmeaBuffer;
+use crate::sentence::{SentenceType, classify};
+use crate::trace::log_trace;
+
+/// Iterator over the `(sentence_begin, sentence_length)` of every complete sentence in a window of a circular buffer.
+///
+/// A sentence runs from `$` up to and including `\n`, and may straddle the wrap point. Bytes outside a sentence are
+/// skipped, and a `$` before the end of a sentence restarts it. A sentence that is still being received when the
+/// window ends is not reported; [`Framer::resume_index`] gives where to start the next window so it is not lost.
+#[derive(Clone, Debug)]
+pub struct Framer<'a, B: NmeaBuffer + ?Sized> {
+    pub(crate) buffer: &'a B,
+    index: usize,
+    remaining: usize,
+}
+
+impl<'a, B: NmeaBuffer + ?Sized> Framer<'a, B> {
+    /// Scan from `begin` up to the DMA write position.
+    ///
+    /// ### Arguments
+    /// * `buffer` - A circular buffer containing NMEA 0183 data.
+    /// * `begin` - Index to start scanning from, usually the [`Framer::resume_index`] of the previous window.
+    /// * `ndtr` - Current value of the DMA NDTR register, counting down from the buffer length. A value beyond the
+    ///   buffer length, or an empty buffer, scans nothing.
+    #[inline]
+    pub fn new(buffer: &'a B, begin: usize, ndtr: u16) -> Framer<'a, B> {
+        let len = buffer.len();
+        let begin = begin.checked_rem(len).unwrap_or(0);
+        Framer {
+            buffer,
+            index: begin,
+            remaining: ndtr_write_index(len, ndtr).map_or(0, |write_index| (write_index + len - begin) % len),
+        }
+    }
+
+    /// Scan `length` bytes starting at `begin`.
+    #[inline]
+    pub(crate) fn with_length(buffer: &'a B, begin: usize, length: usize) -> Framer<'a, B> {
+        let len = buffer.len();
+        Framer {
+            buffer,
+            index: begin.checked_rem(len).unwrap_or(0),
+            remaining: if len == 0 { 0 } else { length },
+        }
+    }
+
+    /// Scan the bytes received between two NDTR snapshots.
+    ///
+    /// ### Arguments
+    /// * `buffer` - A circular buffer containing NMEA 0183 data.
+    /// * `previous_ndtr` - Value of the DMA NDTR register at the previous scan.
+    /// * `ndtr` - Current value of the DMA NDTR register.
+    #[inline]
+    pub fn between(buffer: &'a B, previous_ndtr: u16, ndtr: u16) -> Framer<'a, B> {
+        match ndtr_write_index(buffer.len(), previous_ndtr) {
+            Some(begin) => Framer::new(buffer, begin, ndtr),
+            None => Framer::with_length(buffer, 0, 0),
+        }
+    }
+
+    /// Index where the next window should begin: the start of an incomplete sentence, or the end of this window.
+    ///
+    /// Only meaningful once the iterator has returned `None`.
+    #[inline]
+    pub fn resume_index(&self) -> usize {
+        self.index.checked_rem(self.buffer.len()).unwrap_or(0)
+    }
+}
+
+/// Index the DMA will write next, from an NDTR counting down from the buffer length.
+///
+/// ### Returns
+/// `None` if the buffer is empty or `ndtr` exceeds its length, e.g. a spurious read, which callers treat as nothing
+/// received.
+#[inline]
+pub(crate) fn ndtr_write_index(len: usize, ndtr: u16) -> Option<usize> {
+    len.checked_sub(ndtr as usize)?.checked_rem(len)
+}
+
+impl<B: NmeaBuffer + ?Sized> Iterator for Framer<'_, B> {
+    type Item = (usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, usize)> {
+        'sentence: loop {
+            // Find the start of a sentence
+            let skipped = sentence_start_offset(self.buffer, self.index, self.remaining);
+            self.index += skipped;
+            self.remaining -= skipped;
+            if self.remaining == 0 {
+                return None;
+            }
+
+            // Find the end of the sentence
+            let begin = self.index;
+            let mut length = 1;
+            while length < self.remaining {
+                match self.buffer.at(begin + length) {
+                    b'\n' => {
+                        length += 1;
+                        self.index += length;
+                        self.remaining -= length;
+                        log_trace!("sentence at {} length {}", begin % self.buffer.len(), length);
+                        return Some((begin % self.buffer.len(), length));
+                    }
+                    b'$' => {
+                        self.index += length;
+                        self.remaining -= length;
+                        continue 'sentence;
+                    }
+                    _ => length += 1,
+                }
+            }
+
+            // Incomplete, leave the index at its start
+            return None;
+        }
+    }
+}
+
+/// Find the first `$` in a region of a circular buffer.
+///
+/// Compares a word at a time when the buffer is stored contiguously, so long runs of binary data or noise between
+/// sentences are skipped quickly.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `from` - Index to start scanning from.
+/// * `len` - Number of bytes to scan, which may wrap.
+///
+/// ### Returns
+/// Index of the `$` in the buffer, or `None` if the region does not contain one or the buffer is empty.
+#[inline]
+pub fn find_sentence_start<B: NmeaBuffer + ?Sized>(buffer: &B, from: usize, len: usize) -> Option<usize> {
+    let offset = sentence_start_offset(buffer, from, len);
+    (offset < len).then(|| (from + offset) % buffer.len())
+}
+
+/// Offset of the first `$` in the `len` bytes from `from`, or `len` if there is none.
+#[inline]
+fn sentence_start_offset<B: NmeaBuffer + ?Sized>(buffer: &B, from: usize, len: usize) -> usize {
+    if buffer.is_empty() {
+        return len;
+    }
+    let Some(slice) = buffer.contiguous() else {
+        return (0..len).find(|&offset| buffer.at(from + offset) == b'$').unwrap_or(len);
+    };
+    // Search up to the wrap point, then from the start of the buffer
+    let start = from % slice.len();
+    let first = len.min(slice.len() - start);
+    if let Some(offset) = find_dollar(&slice[start..start + first]) {
+        return offset;
+    }
+    let second = (len - first).min(start);
+    find_dollar(&slice[..second]).map_or(len, |offset| first + offset)
+}
+
+/// Index of the first `$` in `bytes`, comparing eight bytes at a time.
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn find_dollar(bytes: &[u8]) -> Option<usize> {
+    const ONES: u64 = 0x0101_0101_0101_0101;
+    const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+    let mut words = bytes.chunks_exact(8);
+    for (i, word) in words.by_ref().enumerate() {
+        // Lanes equal to `$` become zero, and the lowest zero lane sets its high bit exactly
+        let word = u64::from_le_bytes([word[0], word[1], word[2], word[3], word[4], word[5], word[6], word[7]]);
+        let word = word ^ (ONES * b'$' as u64);
+        let zeros = word.wrapping_sub(ONES) & !word & HIGH_BITS;
+        if zeros != 0 {
+            return Some(i * 8 + zeros.trailing_zeros() as usize / 8);
+        }
+    }
+    let tail = words.remainder();
+    tail.iter().position(|&b| b == b'$').map(|offset| bytes.len() - tail.len() + offset)
+}
+
+/// Index of the first `$` in `bytes`, comparing a vector at a time.
+#[cfg(feature = "simd")]
+#[inline]
+fn find_dollar(bytes: &[u8]) -> Option<usize> {
+    crate::simd::find_byte(bytes, b'$')
+}
+
+/// Iterator over the `(sentence_begin, sentence_type)` of every complete sentence in a window of a circular buffer.
+///
+/// Frames sentences as [`Framer`] does, for dispatching several sentences delivered by one idle line interrupt.
+#[derive(Clone, Debug)]
+pub struct SentenceIter<'a, B: NmeaBuffer + ?Sized> {
+    framer: Framer<'a, B>,
+}
+
+impl<'a, B: NmeaBuffer + ?Sized> SentenceIter<'a, B> {
+    /// Iterate from `begin` up to the DMA write position.
+    ///
+    /// ### Arguments
+    /// * `buffer` - A circular buffer containing NMEA 0183 data.
+    /// * `begin` - Index to start scanning from, usually the [`SentenceIter::resume_index`] of the previous window.
+    /// * `ndtr` - Current value of the DMA NDTR register, counting down from the buffer length.
+    #[inline]
+    pub fn new(buffer: &'a B, begin: usize, ndtr: u16) -> SentenceIter<'a, B> {
+        SentenceIter {
+            framer: Framer::new(buffer, begin, ndtr),
+        }
+    }
+
+    /// Index where the next window should begin, see [`Framer::resume_index`].
+    #[inline]
+    pub fn resume_index(&self) -> usize {
+        self.framer.resume_index()
+    }
+}
+
+impl<B: NmeaBuffer + ?Sized> Iterator for SentenceIter<'_, B> {
+    type Item = (usize, SentenceType);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, SentenceType)> {
+        let (sentence_begin, _) = self.framer.next()?;
+        Some((sentence_begin, classify(self.framer.buffer, sentence_begin)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_framer() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, WINDOW, i);
+            let ndtr = (1024 - ((i + WINDOW.len()) & 1023)) as u16;
+            let mut framer = Framer::new(&buffer, i, ndtr);
+            for (offset, length) in SENTENCES.iter() {
+                assert_eq!(framer.next(), Some(((i + offset) & 1023, *length)));
+            }
+            assert_eq!(framer.next(), None);
+            assert_eq!(framer.resume_index(), (i + INCOMPLETE) & 1023);
+        }
+    }
+
+    #[test]
+    fn test_framer_between() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, WINDOW, i);
+            let previous_ndtr = (1024 - i) as u16;
+            let ndtr = (1024 - ((i + WINDOW.len()) & 1023)) as u16;
+            let framer = Framer::between(&buffer, previous_ndtr, ndtr);
+            assert_eq!(framer.count(), SENTENCES.len());
+        }
+    }
+
+    #[test]
+    fn test_framer_empty_window() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, WINDOW, 0);
+        let mut framer = Framer::new(&buffer, 100, 924);
+        assert_eq!(framer.next(), None);
+        assert_eq!(framer.resume_index(), 100);
+    }
+
+    #[test]
+    fn test_framer_out_of_range() {
+        let mut buffer: [u8; 512] = [0; 512];
+        buffer[..WINDOW.len()].copy_from_slice(WINDOW);
+        let mut framer = Framer::new(&buffer, 100, 600);
+        assert_eq!(framer.next(), None);
+        assert_eq!(framer.resume_index(), 100);
+        assert_eq!(Framer::between(&buffer, 600, 300).count(), 0);
+        assert_eq!(Framer::between(&buffer, 512, 600).count(), 0);
+
+        let mut framer = Framer::new(&[][..], 100, 0);
+        assert_eq!(framer.next(), None);
+        assert_eq!(framer.resume_index(), 0);
+        assert_eq!(Framer::between(&[][..], 0, 0).count(), 0);
+        assert_eq!(Framer::with_length(&[][..], 7, 10).count(), 0);
+    }
+
+    #[test]
+    fn test_sentence_iter() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, WINDOW, i);
+            let ndtr = (1024 - ((i + WINDOW.len()) & 1023)) as u16;
+            let mut sentences = SentenceIter::new(&buffer, i, ndtr);
+            for ((offset, _), sentence_type) in SENTENCES.iter().zip(SENTENCE_TYPES.iter()) {
+                assert_eq!(sentences.next(), Some(((i + offset) & 1023, *sentence_type)));
+            }
+            assert_eq!(sentences.next(), None);
+            assert_eq!(sentences.resume_index(), (i + INCOMPLETE) & 1023);
+        }
+    }
+
+    #[test]
+    fn test_sentence_iter_out_of_range() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, WINDOW, 0);
+        let mut sentences = SentenceIter::new(&buffer, 0, 1025);
+        assert_eq!(sentences.next(), None);
+        assert_eq!(sentences.resume_index(), 0);
+        let mut sentences = SentenceIter::new(&[][..], 10, 0);
+        assert_eq!(sentences.next(), None);
+        assert_eq!(sentences.resume_index(), 0);
+    }
+
+    #[test]
+    fn test_find_sentence_start() {
+        for i in 0..1024 {
+            let mut buffer: [u8; 1024] = [0; 1024];
+            shift_buffer(&mut buffer, NOISE, i);
+            assert_eq!(find_sentence_start(&buffer, i, NOISE.len()), Some((i + 21) & 1023));
+            assert_eq!(find_sentence_start(&buffer, i, 21), None);
+            assert_eq!(find_sentence_start(&buffer, i + 21, 1), Some((i + 21) & 1023));
+            assert_eq!(find_sentence_start(&buffer, i + 22, 1023), None);
+            assert_eq!(find_sentence_start(&buffer, i + 22, 1024), Some((i + 21) & 1023));
+            assert_eq!(find_sentence_start(&buffer[..], i + 22, 1024), Some((i + 21) & 1023));
+        }
+        assert_eq!(find_sentence_start(&[][..], 0, 0), None);
+        assert_eq!(find_sentence_start(&[][..], 5, 10), None);
+    }
+
+    #[test]
+    fn test_find_dollar() {
+        let mut bytes = [b'x'; 40];
+        assert_eq!(find_dollar(&bytes), None);
+        for i in 0..bytes.len() {
+            bytes[i] = b'$';
+            assert_eq!(find_dollar(&bytes), Some(i));
+            assert_eq!(find_dollar(&bytes[..i]), None);
+            // Lanes just below `$` must not be reported
+            bytes[i] = b'#';
+            assert_eq!(find_dollar(&bytes), None);
+            bytes[i] = b'x';
+        }
+    }
+
+    const NOISE: &[u8] = b"\xd3\x00\x13\x3e\xd0\x00\x03\x8a\x0e\xde\xef\x34\xb4\xbd\x62\xac\x09\x41\x98\x6f\x33$GNGGA";
+
+    const WINDOW: &[u8] = b"*59\r\n\
+        $GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNGGA,0512\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGSA,A,3,";
+
+    const SENTENCES: [(usize, usize); 3] = [(5, 75), (91, 74), (165, 42)];
+
+    const SENTENCE_TYPES: [SentenceType; 3] = [SentenceType::Gga, SentenceType::Rmc, SentenceType::Gga];
+
+    const INCOMPLETE: usize = 207;
+}
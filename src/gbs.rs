@@ -0,0 +1,117 @@
+//! Detect and parse GBS (GNSS Satellite Fault Detection) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, parse_signed_decimal, skip_field};
+
+/// Determine if the sentence is a GBS sentence.
+#[inline]
+pub fn is_gbs<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'G'
+        && byte_at(buffer, sentence_begin + 4) == b'B'
+        && byte_at(buffer, sentence_begin + 5) == b'S'
+}
+
+/// Extract RAIM expected errors and the most likely failed satellite from a GBS sentence in a circular buffer.
+///
+/// The fault block layout is:
+/// * `[0..4]` - Expected error in latitude in millimetres, signed big-endian.
+/// * `[4..8]` - Expected error in longitude in millimetres, signed big-endian.
+/// * `[8..12]` - Expected error in altitude in millimetres, signed big-endian.
+/// * `[12]` - PRN of the most likely failed satellite. 0 if no satellite is flagged.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GBS sentence in the buffer.
+/// * `fault_block` - Output buffer where parsed data will be stored (13 bytes).
+///
+/// ### Returns
+/// If the sentence contains expected errors.
+#[inline]
+pub fn extract_gbs<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, fault_block: &mut [u8; 13]) -> bool {
+    // Skip time
+    let index = skip_field(buffer, sentence_begin + 7);
+
+    // Check latitude error field
+    if byte_at(buffer, index) == b',' {
+        return false;
+    }
+
+    let (lat_error, end) = parse_signed_decimal(buffer, index, 3);
+    fault_block[0] = (lat_error >> 24) as u8;
+    fault_block[1] = (lat_error >> 16) as u8;
+    fault_block[2] = (lat_error >> 8) as u8;
+    fault_block[3] = lat_error as u8;
+
+    let (lon_error, end) = parse_signed_decimal(buffer, skip_field(buffer, end), 3);
+    fault_block[4] = (lon_error >> 24) as u8;
+    fault_block[5] = (lon_error >> 16) as u8;
+    fault_block[6] = (lon_error >> 8) as u8;
+    fault_block[7] = lon_error as u8;
+
+    let (alt_error, end) = parse_signed_decimal(buffer, skip_field(buffer, end), 3);
+    fault_block[8] = (alt_error >> 24) as u8;
+    fault_block[9] = (alt_error >> 16) as u8;
+    fault_block[10] = (alt_error >> 8) as u8;
+    fault_block[11] = alt_error as u8;
+
+    let (failed_prn, _) = parse_decimal(buffer, skip_field(buffer, end), 0);
+    fault_block[12] = failed_prn as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_gbs() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GBS_VALID[0].0, i);
+            assert!(!is_gbs(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_gbs(&buffer, i));
+            assert!(!is_gbs(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_gbs_empty() {
+        let mut fault_block = [0; 13];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GBS_EMPTY, i);
+            let parsed = extract_gbs(&buffer, i, &mut fault_block);
+            assert!(!parsed);
+            assert_eq!(fault_block, [0; 13]);
+        }
+    }
+
+    #[test]
+    fn test_gbs_valid() {
+        let mut fault_block = [0; 13];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_fault_block) in GBS_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gbs(&buffer, i, &mut fault_block);
+                assert!(parsed);
+                assert_eq!(fault_block, *expected_fault_block);
+            }
+        }
+    }
+
+    const GBS_EMPTY: &[u8] = b"$GNGBS,051154.000,,,,,,,*45\r\n";
+
+    const GBS_VALID: [(&[u8], [u8; 13]); 2] = [
+        (
+            b"$GPGBS,015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972*4D\r\n",
+            [255, 255, 255, 225, 255, 255, 255, 70, 0, 0, 0, 219, 19],
+        ),
+        // NMEA 4.10 with system and signal IDs
+        (
+            b"$GNGBS,051200.993,1.3,1.1,2.8,,,,,1,1*62\r\n",
+            [0, 0, 5, 20, 0, 0, 4, 76, 0, 0, 10, 240, 0],
+        ),
+    ];
+}
@@ -0,0 +1,125 @@
+//! Detect and parse GLL (Geographic Position - Latitude/Longitude) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_latitude, parse_longitude};
+
+/// Determine if the sentence is a GLL sentence.
+#[inline]
+pub fn is_gll<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'G'
+        && byte_at(buffer, sentence_begin + 4) == b'L'
+        && byte_at(buffer, sentence_begin + 5) == b'L'
+}
+
+/// Extract position data from a GLL sentence in a circular buffer.
+///
+/// The position block has the same layout as [`extract_gga`](crate::extract_gga). GLL does not carry HDOP so
+/// byte 9 is set to 0.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GLL sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+///
+/// ### Returns
+/// If the sentence contains a valid position.
+#[inline]
+pub fn extract_gll<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, position_block: &mut [u8; 10]) -> bool {
+    // Check latitude field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        // No latitude field, no fix
+        return false;
+    }
+
+    // Check status field
+    if byte_at(buffer, sentence_begin + 45) != b'A' {
+        return false;
+    }
+
+    // Parse latitude
+    let lat = parse_latitude(buffer, sentence_begin + 7);
+
+    position_block[0] = (lat >> 24) as u8;
+    position_block[1] = (lat >> 16) as u8;
+    position_block[2] = (lat >> 8) as u8;
+    position_block[3] = lat as u8;
+
+    // Latitude hemisphere
+    position_block[8] = ((byte_at(buffer, sentence_begin + 18) == b'N') as u8) << 1;
+
+    // Parse longitude
+    let lon = parse_longitude(buffer, sentence_begin + 20);
+
+    position_block[4] = (lon >> 24) as u8;
+    position_block[5] = (lon >> 16) as u8;
+    position_block[6] = (lon >> 8) as u8;
+    position_block[7] = lon as u8;
+
+    // Longitude hemisphere
+    position_block[8] |= (byte_at(buffer, sentence_begin + 32) == b'E') as u8;
+
+    // No HDOP
+    position_block[9] = 0;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_gll() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GLL_VALID[0].0, i);
+            assert!(!is_gll(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_gll(&buffer, i));
+            assert!(!is_gll(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_gll_invalid() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in GLL_INVALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gll(&buffer, i, &mut position_block);
+                assert!(!parsed);
+                assert_eq!(position_block, [0; 10]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gll_valid() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_position_block) in GLL_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gll(&buffer, i, &mut position_block);
+                assert!(parsed);
+                assert_eq!(position_block, *expected_position_block);
+            }
+        }
+    }
+
+    const GLL_INVALID: [&[u8]; 2] = [
+        b"$GNGLL,,,,,051154.000,V,N*60\r\n",
+        b"$GNGLL,3615.12012,S,06357.25158,W,181501.000,V,N*51\r\n",
+    ];
+
+    const GLL_VALID: [(&[u8], [u8; 10]); 2] = [
+        (
+            b"$GNGLL,2734.21973,S,15303.08927,E,051200.993,A,A*53\r\n",
+            [162, 248, 225, 210, 91, 54, 169, 63, 1, 0],
+        ),
+        (
+            b"$GPGLL,3944.50086,N,10459.16654,W,181501.000,A,D*4A\r\n",
+            [235, 28, 78, 124, 62, 87, 107, 238, 2, 0],
+        ),
+    ];
+}
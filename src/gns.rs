@@ -0,0 +1,145 @@
+//! Detect and parse GNS (GNSS Fix Data) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{MAX_SENTENCE_LENGTH, byte_at, parse_decimal, parse_latitude, parse_longitude, skip_field};
+
+/// Determine if the sentence is a GNS sentence.
+#[inline]
+pub fn is_gns<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'G'
+        && byte_at(buffer, sentence_begin + 4) == b'N'
+        && byte_at(buffer, sentence_begin + 5) == b'S'
+}
+
+/// Extract position data from a GNS sentence in a circular buffer.
+///
+/// The position block has the same layout as [`extract_gga`](crate::extract_gga).
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GNS sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+///
+/// ### Returns
+/// If any constellation in the mode indicator has a fix.
+#[inline]
+pub fn extract_gns<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, position_block: &mut [u8; 10]) -> bool {
+    // Check time field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        // No time field, assume no fix
+        return false;
+    }
+
+    // Check latitude field
+    if byte_at(buffer, sentence_begin + 18) == b',' {
+        // No latitude field, no fix
+        return false;
+    }
+
+    // Mode indicator has one character per constellation, N if that constellation has no fix
+    let mut index = sentence_begin + 45;
+    let mut fix = false;
+    for _ in 0..MAX_SENTENCE_LENGTH {
+        let mode = byte_at(buffer, index);
+        if mode == b',' || mode == b'*' {
+            break;
+        }
+        fix |= mode != b'N';
+        index += 1;
+    }
+    if !fix {
+        return false;
+    }
+
+    // Parse latitude
+    let lat = parse_latitude(buffer, sentence_begin + 18);
+
+    position_block[0] = (lat >> 24) as u8;
+    position_block[1] = (lat >> 16) as u8;
+    position_block[2] = (lat >> 8) as u8;
+    position_block[3] = lat as u8;
+
+    // Latitude hemisphere
+    position_block[8] = ((byte_at(buffer, sentence_begin + 29) == b'N') as u8) << 1;
+
+    // Parse longitude
+    let lon = parse_longitude(buffer, sentence_begin + 31);
+
+    position_block[4] = (lon >> 24) as u8;
+    position_block[5] = (lon >> 16) as u8;
+    position_block[6] = (lon >> 8) as u8;
+    position_block[7] = lon as u8;
+
+    // Longitude hemisphere
+    position_block[8] |= (byte_at(buffer, sentence_begin + 43) == b'E') as u8;
+
+    // Skip satellites used, HDOP in tenths
+    let (hdop, _) = parse_decimal(buffer, skip_field(buffer, skip_field(buffer, index)), 1);
+    position_block[9] = if hdop < 256 { hdop as u8 } else { 255 };
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_gns() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GNS_WITH_FIX[0].0, i);
+            assert!(!is_gns(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_gns(&buffer, i));
+            assert!(!is_gns(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_gns_no_fix() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in GNS_NO_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gns(&buffer, i, &mut position_block);
+                assert!(!parsed);
+                assert_eq!(position_block, [0; 10]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gns_with_fix() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_position_block) in GNS_WITH_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gns(&buffer, i, &mut position_block);
+                assert!(parsed);
+                assert_eq!(position_block, *expected_position_block);
+            }
+        }
+    }
+
+    const GNS_NO_FIX: [&[u8]; 2] = [
+        b"$GNGNS,051154.000,,,,,NNNN,00,25.5,,,,,V*2F\r\n",
+        b"$GNGNS,181501.000,3615.12012,S,06357.25158,W,NNNN,00,99.9,84.6,41.1,,,V*1B\r\n",
+    ];
+
+    const GNS_WITH_FIX: [(&[u8], [u8; 10]); 3] = [
+        (
+            b"$GNGNS,051200.993,2734.21973,S,15303.08927,E,AAN,07,2.8,103.4,41.1,,,V*47\r\n",
+            [162, 248, 225, 210, 91, 54, 169, 63, 1, 28],
+        ),
+        (
+            b"$GPGNS,181501.000,3944.50086,N,10459.16654,W,D,03,2.10,84.6,41.1,,,S*55\r\n",
+            [235, 28, 78, 124, 62, 87, 107, 238, 2, 21],
+        ),
+        (
+            b"$GNGNS,181501.000,3615.12012,S,06357.25158,W,NNRN,03,39.9,84.6,41.1,,,S*0B\r\n",
+            [215, 122, 90, 248, 37, 228, 101, 102, 0, 255],
+        ),
+    ];
+}
@@ -0,0 +1,122 @@
+//! Compile-time extraction, for computing test vectors and reference fixes in `const` items.
+use crate::parse::parse_8_digits;
+
+/// Read a byte from a circular buffer held in a fixed array.
+#[inline(always)]
+const fn byte<const N: usize>(buffer: &[u8; N], index: usize) -> u8 {
+    buffer[index % N]
+}
+
+/// Read an ASCII digit from a circular buffer held in a fixed array.
+#[inline(always)]
+const fn digit<const N: usize>(buffer: &[u8; N], index: usize) -> u32 {
+    byte(buffer, index).wrapping_sub(b'0') as u32
+}
+
+/// Pack the digits at `offsets` from `index` into a word, the first digit in the lowest lane.
+#[inline(always)]
+const fn lanes<const N: usize>(buffer: &[u8; N], index: usize, offsets: [usize; 8]) -> u64 {
+    u64::from_le_bytes([
+        byte(buffer, index + offsets[0]),
+        byte(buffer, index + offsets[1]),
+        byte(buffer, index + offsets[2]),
+        byte(buffer, index + offsets[3]),
+        byte(buffer, index + offsets[4]),
+        byte(buffer, index + offsets[5]),
+        byte(buffer, index + offsets[6]),
+        byte(buffer, index + offsets[7]),
+    ])
+}
+
+/// Parse a `ddmm.mmmmm` latitude field starting at `index` into a 9-digit integer.
+#[inline(always)]
+const fn parse_latitude_const<const N: usize>(buffer: &[u8; N], index: usize) -> u32 {
+    // Skip decimal point
+    let high = parse_8_digits(lanes(buffer, index, [0, 1, 2, 3, 5, 6, 7, 8]));
+    high.wrapping_mul(10).wrapping_add(digit(buffer, index + 9)).wrapping_mul(10)
+}
+
+/// Parse a `dddmm.mmmmm` longitude field starting at `index` into a 10-digit integer.
+#[inline(always)]
+const fn parse_longitude_const<const N: usize>(buffer: &[u8; N], index: usize) -> u32 {
+    // Skip decimal point
+    let high = parse_8_digits(lanes(buffer, index, [0, 1, 2, 3, 4, 6, 7, 8]));
+    high.wrapping_mul(100).wrapping_add(digit(buffer, index + 9) * 10 + digit(buffer, index + 10))
+}
+
+/// Extract position data from a GGA sentence in a fixed array at compile time.
+///
+/// Produces the same position block as [`extract_gga`](crate::extract_gga) without the `strict` checks, so firmware can
+/// bake in reference fixes for a self-test.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data, or a single sentence.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+///
+/// ### Returns
+/// The position block, or `None` if the sentence does not contain a GNSS fix.
+#[inline]
+pub const fn extract_gga_const<const N: usize>(buffer: &[u8; N], sentence_begin: usize) -> Option<[u8; 10]> {
+    // No time or latitude field, no fix
+    if byte(buffer, sentence_begin + 7) == b',' || byte(buffer, sentence_begin + 18) == b',' {
+        return None;
+    }
+
+    let lat = parse_latitude_const(buffer, sentence_begin + 18);
+    let lon = parse_longitude_const(buffer, sentence_begin + 31);
+    let flags =
+        ((byte(buffer, sentence_begin + 29) == b'N') as u8) << 1 | (byte(buffer, sentence_begin + 43) == b'E') as u8;
+
+    let hdop = if byte(buffer, sentence_begin + 51) == b'.' {
+        // Integer part is single digit
+        digit(buffer, sentence_begin + 50) * 10 + digit(buffer, sentence_begin + 52)
+    } else {
+        // Integer part is double digit
+        let hdop = digit(buffer, sentence_begin + 50) * 100
+            + digit(buffer, sentence_begin + 51) * 10
+            + digit(buffer, sentence_begin + 53);
+        if hdop < 256 { hdop } else { 255 }
+    };
+
+    let lat = lat.to_be_bytes();
+    let lon = lon.to_be_bytes();
+    Some([
+        lat[0], lat[1], lat[2], lat[3], lon[0], lon[1], lon[2], lon[3], flags, hdop as u8,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_gga;
+    use crate::tests::{GGA_NO_FIX, GGA_WITH_FIX, shift_buffer};
+
+    /// Evaluated by the compiler, so a regression fails the build.
+    const GOLDEN_BLOCK: Option<[u8; 10]> = extract_gga_const(GGA_WITH_FIX_ARRAY, 0);
+
+    #[test]
+    fn test_extract_gga_const() {
+        assert_eq!(GOLDEN_BLOCK, Some([162, 248, 225, 210, 91, 54, 169, 63, 1, 28]));
+        assert_eq!(const { extract_gga_const(GGA_NO_FIX_ARRAY, 0) }, None);
+
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut position_block = [0; 10];
+        for i in 0..1024 {
+            for sentence in [GGA_WITH_FIX, GGA_WIDE_HDOP] {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(extract_gga(&buffer, i, &mut position_block));
+                assert_eq!(extract_gga_const(&buffer, i), Some(position_block));
+            }
+
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert_eq!(extract_gga_const(&buffer, i), None);
+        }
+    }
+
+    /// The shared sentences as arrays, which `extract_gga_const` takes.
+    const GGA_WITH_FIX_ARRAY: &[u8; 75] = GGA_WITH_FIX.as_array().unwrap();
+
+    const GGA_NO_FIX_ARRAY: &[u8; 42] = GGA_NO_FIX.as_array().unwrap();
+
+    const GGA_WIDE_HDOP: &[u8] = b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,1,03,39.9,84.6,M,41.1,M,,*6E\r\n";
+}
@@ -0,0 +1,113 @@
+//! Detect and parse GRS (GNSS Range Residuals) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, digit_at, parse_signed_decimal, skip_field};
+
+/// Determine if the sentence is a GRS sentence.
+#[inline]
+pub fn is_grs<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'G'
+        && byte_at(buffer, sentence_begin + 4) == b'R'
+        && byte_at(buffer, sentence_begin + 5) == b'S'
+}
+
+/// Extract range residuals from a GRS sentence in a circular buffer.
+///
+/// The residual block layout is:
+/// * `[0]` - Mode: 0 if the residuals were used to calculate the position, 1 if they were recomputed after it.
+/// * `[1..25]` - 12 range residuals in centimetres, signed big-endian, in the same satellite order as GSA. 0 if the
+///   field is empty.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GRS sentence in the buffer.
+/// * `residual_block` - Output buffer where parsed data will be stored (25 bytes).
+///
+/// ### Returns
+/// The number of residual fields that were not empty. 0 if the mode field is empty, in which case the block is left
+/// untouched.
+#[inline]
+pub fn extract_grs<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, residual_block: &mut [u8; 25]) -> usize {
+    // Skip time
+    let index = skip_field(buffer, sentence_begin + 7);
+
+    // Check mode field
+    if byte_at(buffer, index) == b',' {
+        return 0;
+    }
+    residual_block[0] = digit_at(buffer, index) as u8;
+
+    let mut count = 0;
+    let mut index = skip_field(buffer, index);
+    for i in 0..12 {
+        count += (byte_at(buffer, index) != b',' && byte_at(buffer, index) != b'*') as usize;
+        let (residual, end) = parse_signed_decimal(buffer, index, 2);
+        let residual = residual.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        residual_block[1 + 2 * i] = (residual >> 8) as u8;
+        residual_block[2 + 2 * i] = residual as u8;
+        index = skip_field(buffer, end);
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_grs() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GRS[0].0, i);
+            assert!(!is_grs(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_grs(&buffer, i));
+            assert!(!is_grs(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_grs_empty() {
+        let mut residual_block = [0; 25];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GRS_EMPTY, i);
+            let count = extract_grs(&buffer, i, &mut residual_block);
+            assert_eq!(count, 0);
+            assert_eq!(residual_block, [0; 25]);
+        }
+    }
+
+    #[test]
+    fn test_grs() {
+        let mut residual_block = [0; 25];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_residual_block, expected_count) in GRS.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let count = extract_grs(&buffer, i, &mut residual_block);
+                assert_eq!(count, *expected_count);
+                assert_eq!(residual_block, *expected_residual_block);
+            }
+        }
+    }
+
+    const GRS_EMPTY: &[u8] = b"$GNGRS,051154.000,,,,,,,,,,,,,*55\r\n";
+
+    const GRS: [(&[u8], [u8; 25], usize); 2] = [
+        (
+            b"$GPGRS,220320.0,0,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,*79\r\n",
+            [
+                0, 255, 176, 255, 236, 255, 246, 255, 236, 0, 80, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            ],
+            6,
+        ),
+        // NMEA 4.10 with system and signal IDs
+        (
+            b"$GNGRS,051200.993,1,12.35,-3.2,0.05,,,,,,,,,,1,1*57\r\n",
+            [
+                1, 4, 211, 254, 192, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            ],
+            3,
+        ),
+    ];
+}
@@ -0,0 +1,122 @@
+//! Detect and parse GSA (GNSS DOP and Active Satellites) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, digit_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a GSA sentence.
+#[inline]
+pub fn is_gsa<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'G'
+        && byte_at(buffer, sentence_begin + 4) == b'S'
+        && byte_at(buffer, sentence_begin + 5) == b'A'
+}
+
+/// Extract fix mode and dilution of precision values from a GSA sentence in a circular buffer.
+///
+/// The DOP block layout is:
+/// * `[0]` - Fix mode: 1 = no fix, 2 = 2D, 3 = 3D.
+/// * `[1..3]` - PDOP in hundredths, big-endian.
+/// * `[3..5]` - HDOP in hundredths, big-endian.
+/// * `[5..7]` - VDOP in hundredths, big-endian.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GSA sentence in the buffer.
+/// * `dop_block` - Output buffer where parsed data will be stored (7 bytes).
+///
+/// ### Returns
+/// If the fix mode is 2D or 3D.
+#[inline]
+pub fn extract_gsa<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, dop_block: &mut [u8; 7]) -> bool {
+    // Check fix mode field
+    if byte_at(buffer, sentence_begin + 9) == b',' {
+        return false;
+    }
+    let mode = digit_at(buffer, sentence_begin + 9) as u8;
+    dop_block[0] = mode;
+
+    // Skip the 12 satellite ID fields, unused ones are empty
+    let mut index = sentence_begin + 11;
+    for _ in 0..12 {
+        index = skip_field(buffer, index);
+    }
+
+    let (pdop, end) = parse_decimal(buffer, index, 2);
+    let pdop = if pdop < 65536 { pdop as u16 } else { 65535 };
+    dop_block[1] = (pdop >> 8) as u8;
+    dop_block[2] = pdop as u8;
+
+    let (hdop, end) = parse_decimal(buffer, skip_field(buffer, end), 2);
+    let hdop = if hdop < 65536 { hdop as u16 } else { 65535 };
+    dop_block[3] = (hdop >> 8) as u8;
+    dop_block[4] = hdop as u8;
+
+    let (vdop, _) = parse_decimal(buffer, skip_field(buffer, end), 2);
+    let vdop = if vdop < 65536 { vdop as u16 } else { 65535 };
+    dop_block[5] = (vdop >> 8) as u8;
+    dop_block[6] = vdop as u8;
+
+    mode >= 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_gsa() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GSA_WITH_FIX[0].0, i);
+            assert!(!is_gsa(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_gsa(&buffer, i));
+            assert!(!is_gsa(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_gsa_no_fix() {
+        let mut dop_block = [0; 7];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GSA_NO_FIX.0, i);
+            let parsed = extract_gsa(&buffer, i, &mut dop_block);
+            assert!(!parsed);
+            assert_eq!(dop_block, GSA_NO_FIX.1);
+        }
+    }
+
+    #[test]
+    fn test_gsa_with_fix() {
+        let mut dop_block = [0; 7];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_dop_block) in GSA_WITH_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gsa(&buffer, i, &mut dop_block);
+                assert!(parsed);
+                assert_eq!(dop_block, *expected_dop_block);
+            }
+        }
+    }
+
+    const GSA_NO_FIX: (&[u8], [u8; 7]) = (
+        b"$GNGSA,A,1,,,,,,,,,,,,,99.99,99.99,99.99,1*33\r\n",
+        [1, 39, 15, 39, 15, 39, 15],
+    );
+
+    const GSA_WITH_FIX: [(&[u8], [u8; 7]); 3] = [
+        (
+            b"$GNGSA,A,3,10,32,12,24,25,,,,,,,,1.83,0.98,1.55,1*09\r\n",
+            [3, 0, 183, 0, 98, 0, 155],
+        ),
+        (
+            b"$GPGSA,A,2,05,,,,,,,,,,,,12.10,9.95,6.88*37\r\n",
+            [2, 4, 186, 3, 227, 2, 176],
+        ),
+        (
+            b"$GPGSA,M,3,01,02,03,04,05,06,07,08,09,10,11,12,1.0,0.7,0.7*3C\r\n",
+            [3, 0, 100, 0, 70, 0, 70],
+        ),
+    ];
+}
@@ -0,0 +1,120 @@
+//! Detect and parse GST (GNSS Pseudorange Error Statistics) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a GST sentence.
+#[inline]
+pub fn is_gst<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'G'
+        && byte_at(buffer, sentence_begin + 4) == b'S'
+        && byte_at(buffer, sentence_begin + 5) == b'T'
+}
+
+/// Extract position error standard deviations from a GST sentence in a circular buffer.
+///
+/// The error block layout is:
+/// * `[0..4]` - Standard deviation of latitude error in millimetres, big-endian.
+/// * `[4..8]` - Standard deviation of longitude error in millimetres, big-endian.
+/// * `[8..12]` - Standard deviation of altitude error in millimetres, big-endian.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GST sentence in the buffer.
+/// * `error_block` - Output buffer where parsed data will be stored (12 bytes).
+///
+/// ### Returns
+/// If the sentence contains error statistics.
+#[inline]
+pub fn extract_gst<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, error_block: &mut [u8; 12]) -> bool {
+    // Skip time, RMS, semi-major, semi-minor and orientation
+    let mut index = sentence_begin + 7;
+    for _ in 0..5 {
+        index = skip_field(buffer, index);
+    }
+
+    // Check latitude error field
+    if byte_at(buffer, index) == b',' {
+        return false;
+    }
+
+    let (lat_sigma, end) = parse_decimal(buffer, index, 3);
+    error_block[0] = (lat_sigma >> 24) as u8;
+    error_block[1] = (lat_sigma >> 16) as u8;
+    error_block[2] = (lat_sigma >> 8) as u8;
+    error_block[3] = lat_sigma as u8;
+
+    let (lon_sigma, end) = parse_decimal(buffer, skip_field(buffer, end), 3);
+    error_block[4] = (lon_sigma >> 24) as u8;
+    error_block[5] = (lon_sigma >> 16) as u8;
+    error_block[6] = (lon_sigma >> 8) as u8;
+    error_block[7] = lon_sigma as u8;
+
+    let (alt_sigma, _) = parse_decimal(buffer, skip_field(buffer, end), 3);
+    error_block[8] = (alt_sigma >> 24) as u8;
+    error_block[9] = (alt_sigma >> 16) as u8;
+    error_block[10] = (alt_sigma >> 8) as u8;
+    error_block[11] = alt_sigma as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_gst() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GST_VALID[0].0, i);
+            assert!(!is_gst(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_gst(&buffer, i));
+            assert!(!is_gst(&buffer, (i + 1) & 1023));
+            assert!(!crate::is_gsa(&buffer, i));
+        }
+    }
+
+    #[test]
+    fn test_gst_empty() {
+        let mut error_block = [0; 12];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GST_EMPTY, i);
+            let parsed = extract_gst(&buffer, i, &mut error_block);
+            assert!(!parsed);
+            assert_eq!(error_block, [0; 12]);
+        }
+    }
+
+    #[test]
+    fn test_gst_valid() {
+        let mut error_block = [0; 12];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_error_block) in GST_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gst(&buffer, i, &mut error_block);
+                assert!(parsed);
+                assert_eq!(error_block, *expected_error_block);
+            }
+        }
+    }
+
+    const GST_EMPTY: &[u8] = b"$GNGST,051154.000,,,,,,,*53\r\n";
+
+    const GST_VALID: [(&[u8], [u8; 12]); 3] = [
+        (
+            b"$GNGST,051200.993,2.1,1.5,1.2,45.3,1.3,1.1,2.8*42\r\n",
+            [0, 0, 5, 20, 0, 0, 4, 76, 0, 0, 10, 240],
+        ),
+        (
+            b"$GPGST,181501.00,0.006,0.023,0.020,273.6,0.023,0.020,0.031*5F\r\n",
+            [0, 0, 0, 23, 0, 0, 0, 20, 0, 0, 0, 31],
+        ),
+        (
+            b"$GPGST,181501.000,,,,,123.4,99.99,1234.5678*67\r\n",
+            [0, 1, 226, 8, 0, 1, 134, 150, 0, 18, 214, 135],
+        ),
+    ];
+}
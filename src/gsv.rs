@@ -0,0 +1,130 @@
+//! Detect and parse GSV (GNSS Satellites in View) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a GSV sentence.
+#[inline]
+pub fn is_gsv<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'G'
+        && byte_at(buffer, sentence_begin + 4) == b'S'
+        && byte_at(buffer, sentence_begin + 5) == b'V'
+}
+
+/// Extract the satellites in view from a GSV sentence in a circular buffer.
+///
+/// A satellite in view is reported across multiple GSV sentences, each carrying up to 4 satellites.
+///
+/// The header block layout is:
+/// * `[0]` - Total number of GSV sentences in this cycle.
+/// * `[1]` - Index of this sentence, starting at 1.
+/// * `[2]` - Total number of satellites in view.
+///
+/// Each satellite block layout is:
+/// * `[0]` - Satellite PRN.
+/// * `[1]` - Elevation in degrees.
+/// * `[2..4]` - Azimuth in degrees, big-endian.
+/// * `[4]` - C/N0 in dB-Hz. 0 if the satellite is not tracked.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GSV sentence in the buffer.
+/// * `header_block` - Output buffer where the message index fields will be stored (3 bytes).
+/// * `satellite_blocks` - Output buffer where the satellites will be stored (4 blocks of 5 bytes).
+///
+/// ### Returns
+/// The number of satellite blocks written.
+#[inline]
+pub fn extract_gsv<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    header_block: &mut [u8; 3],
+    satellite_blocks: &mut [[u8; 5]; 4],
+) -> usize {
+    let (total, end) = parse_decimal(buffer, sentence_begin + 7, 0);
+    let (number, end) = parse_decimal(buffer, skip_field(buffer, end), 0);
+    let (in_view, mut end) = parse_decimal(buffer, skip_field(buffer, end), 0);
+    header_block[0] = total as u8;
+    header_block[1] = number as u8;
+    header_block[2] = in_view as u8;
+
+    let mut count = 0;
+    while count < 4 && byte_at(buffer, end) == b',' {
+        let (prn, prn_end) = parse_decimal(buffer, end + 1, 0);
+        if byte_at(buffer, prn_end) != b',' {
+            // NMEA 4.10 signal ID, not a satellite
+            break;
+        }
+        let (elevation, elevation_end) = parse_decimal(buffer, prn_end + 1, 0);
+        let (azimuth, azimuth_end) = parse_decimal(buffer, skip_field(buffer, elevation_end), 0);
+        let (snr, snr_end) = parse_decimal(buffer, skip_field(buffer, azimuth_end), 0);
+        end = snr_end;
+
+        let satellite_block = &mut satellite_blocks[count];
+        satellite_block[0] = prn as u8;
+        satellite_block[1] = elevation as u8;
+        satellite_block[2] = (azimuth >> 8) as u8;
+        satellite_block[3] = azimuth as u8;
+        satellite_block[4] = snr as u8;
+        count += 1;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_gsv() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GSV[0].0, i);
+            assert!(!is_gsv(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_gsv(&buffer, i));
+            assert!(!is_gsv(&buffer, (i + 1) & 1023));
+            assert!(!crate::is_gsa(&buffer, i));
+        }
+    }
+
+    #[test]
+    fn test_gsv() {
+        let mut header_block = [0; 3];
+        let mut satellite_blocks = [[0; 5]; 4];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_header_block, expected_satellite_blocks) in GSV.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let count = extract_gsv(&buffer, i, &mut header_block, &mut satellite_blocks);
+                assert_eq!(header_block, *expected_header_block);
+                assert_eq!(&satellite_blocks[..count], *expected_satellite_blocks);
+            }
+        }
+    }
+
+    type GsvCase = (&'static [u8], [u8; 3], &'static [[u8; 5]]);
+
+    const GSV: [GsvCase; 4] = [
+        (
+            b"$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74\r\n",
+            [3, 1, 11],
+            &[
+                [3, 3, 0, 111, 0],
+                [4, 15, 1, 14, 0],
+                [6, 1, 0, 10, 0],
+                [13, 6, 1, 36, 0],
+            ],
+        ),
+        (
+            b"$GPGSV,3,3,11,22,42,067,42,24,14,311,43,27,05,244,*4D\r\n",
+            [3, 3, 11],
+            &[[22, 42, 0, 67, 42], [24, 14, 1, 55, 43], [27, 5, 0, 244, 0]],
+        ),
+        (
+            b"$GLGSV,2,2,06,72,65,211,38,88,17,045,,1*76\r\n",
+            [2, 2, 6],
+            &[[72, 65, 0, 211, 38], [88, 17, 0, 45, 0]],
+        ),
+        (b"$GPGSV,1,1,00*79\r\n", [1, 1, 0], &[]),
+    ];
+}
@@ -0,0 +1,86 @@
+//! Detect and parse HDT (Heading, True) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal};
+
+/// Determine if the sentence is a HDT sentence.
+#[inline]
+pub fn is_hdt<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'H'
+        && byte_at(buffer, sentence_begin + 4) == b'D'
+        && byte_at(buffer, sentence_begin + 5) == b'T'
+}
+
+/// Extract the true heading from a HDT sentence in a circular buffer.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the HDT sentence in the buffer.
+/// * `heading_block` - Output buffer where the heading in hundredths of a degree will be stored, big-endian (2 bytes).
+///
+/// ### Returns
+/// If the sentence contains a heading.
+#[inline]
+pub fn extract_hdt<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, heading_block: &mut [u8; 2]) -> bool {
+    // Check heading field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        return false;
+    }
+
+    // Integer part is 1 to 3 digits
+    let (heading, _) = parse_decimal(buffer, sentence_begin + 7, 2);
+    heading_block[0] = (heading >> 8) as u8;
+    heading_block[1] = heading as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_hdt() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, HDT_VALID[0].0, i);
+            assert!(!is_hdt(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_hdt(&buffer, i));
+            assert!(!is_hdt(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_hdt_empty() {
+        let mut heading_block = [0; 2];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, HDT_EMPTY, i);
+            let parsed = extract_hdt(&buffer, i, &mut heading_block);
+            assert!(!parsed);
+            assert_eq!(heading_block, [0; 2]);
+        }
+    }
+
+    #[test]
+    fn test_hdt_valid() {
+        let mut heading_block = [0; 2];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_heading_block) in HDT_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_hdt(&buffer, i, &mut heading_block);
+                assert!(parsed);
+                assert_eq!(heading_block, *expected_heading_block);
+            }
+        }
+    }
+
+    const HDT_EMPTY: &[u8] = b"$GPHDT,,T*1B\r\n";
+
+    const HDT_VALID: [(&[u8], [u8; 2]); 3] = [
+        (b"$GPHDT,274.07,T*03\r\n", [107, 15]),
+        (b"$HEHDT,5.1,T*2B\r\n", [1, 254]),
+        (b"$GNHDT,45.125,T*1C\r\n", [17, 160]),
+    ];
+}
@@ -0,0 +1,119 @@
+//! GGA fixes from serial drivers implementing `embedded_io::Read`, for receivers without DMA.
+use crate::fix::GgaFix;
+use crate::stream::GgaStream;
+
+/// Number of bytes requested from the reader at a time.
+const CHUNK_LENGTH: usize = 32;
+
+/// Reads bytes from an `embedded_io::Read` implementor and yields the fixes of GGA sentences.
+///
+/// Bytes are read in chunks and fed through [`GgaStream`], so sentences may span any number of reads. Bytes read past
+/// the end of a sentence are kept for the next call.
+#[derive(Debug)]
+pub struct GgaReader<R> {
+    reader: R,
+    stream: GgaStream,
+    chunk: [u8; CHUNK_LENGTH],
+    index: usize,
+    length: usize,
+}
+
+impl<R: embedded_io::Read> GgaReader<R> {
+    /// Create an adapter waiting for the start of a sentence.
+    #[inline]
+    pub const fn new(reader: R) -> GgaReader<R> {
+        GgaReader {
+            reader,
+            stream: GgaStream::new(),
+            chunk: [0; CHUNK_LENGTH],
+            index: 0,
+            length: 0,
+        }
+    }
+
+    /// Read until a GGA sentence containing a fix is complete.
+    ///
+    /// Blocks as the reader does when no bytes are available.
+    ///
+    /// ### Returns
+    /// The fix, `None` if the reader reached the end of its input, or the reader's error.
+    #[inline]
+    pub fn next_fix(&mut self) -> Result<Option<GgaFix>, R::Error> {
+        loop {
+            while self.index < self.length {
+                let byte = self.chunk[self.index];
+                self.index += 1;
+                if let Some(fix) = self.stream.push(byte) {
+                    return Ok(Some(fix));
+                }
+            }
+            self.index = 0;
+            self.length = self.reader.read(&mut self.chunk)?;
+            if self.length == 0 {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Give back the reader. Bytes already read but not yet parsed are lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_gga_struct;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+
+    /// Serial driver returning at most `limit` bytes per read, as a UART FIFO does.
+    struct Uart<'a> {
+        bytes: &'a [u8],
+        limit: usize,
+    }
+
+    impl embedded_io::ErrorType for Uart<'_> {
+        type Error = embedded_io::ErrorKind;
+    }
+
+    impl embedded_io::Read for Uart<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let length = self.limit.min(buf.len()).min(self.bytes.len());
+            buf[..length].copy_from_slice(&self.bytes[..length]);
+            self.bytes = &self.bytes[length..];
+            Ok(length)
+        }
+    }
+
+    #[test]
+    fn test_gga_reader() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_WITH_FIX, 0);
+        let expected_fix = extract_gga_struct(&buffer, 0).unwrap();
+
+        for limit in 1..=STREAM.len() {
+            let mut reader = GgaReader::new(Uart { bytes: STREAM, limit });
+            assert_eq!(reader.next_fix(), Ok(Some(expected_fix)));
+            assert_eq!(reader.next_fix(), Ok(Some(expected_fix)));
+            assert_eq!(reader.next_fix(), Ok(None));
+        }
+    }
+
+    #[test]
+    fn test_gga_reader_slice() {
+        let mut reader = GgaReader::new(STREAM);
+        assert!(reader.next_fix().unwrap().is_some());
+        assert!(reader.next_fix().unwrap().is_some());
+        assert_eq!(reader.next_fix(), Ok(None));
+        assert!(reader.into_inner().is_empty());
+    }
+
+    const STREAM: &[u8] = b"73,M,41.1,M,,*59\r\n\
+        $GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGGA,0512$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNGGA,0512";
+}
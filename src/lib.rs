@@ -1,14 +1,170 @@
-//! Detect if a NMEA sentence is GGA and parse the GGA sentence.
+//! Detect NMEA 0183 sentence types and parse them from a DMA circular buffer.
 #![no_std]
 
+// Log replay and the Python and JavaScript bindings need std
+#[cfg(any(feature = "std", feature = "pyo3", feature = "wasm"))]
+extern crate std;
+
+#[cfg(feature = "embedded-io-async")]
+mod async_io;
+mod batch;
+mod buffer;
+mod checked;
+mod checksum;
+mod compact;
+mod cursor;
+mod dbt;
+mod decode;
+mod degrees;
+mod double;
+mod dpt;
+mod dtm;
+mod emit;
+mod encoder;
+mod extended;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod field;
+mod fix;
+mod frame;
+mod gbs;
+mod gll;
+mod gns;
+mod golden;
+mod grs;
+mod gsa;
+mod gst;
+mod gsv;
+mod hdt;
+#[cfg(feature = "embedded-io")]
+mod io;
+#[cfg(feature = "mavlink")]
+mod mavlink;
+mod mtw;
+mod mwv;
+#[cfg(feature = "embedded-hal-nb")]
+mod nb;
+mod parse;
+#[cfg(feature = "profiling")]
+mod profile;
+#[cfg(feature = "pyo3")]
+mod python;
+#[cfg(feature = "heapless")]
+mod queue;
+mod rmb;
+mod rmc;
+mod rot;
+mod scanner;
+mod sentence;
+#[cfg(feature = "critical-section")]
+mod shared;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "simulator")]
+mod simulator;
+#[cfg(feature = "std")]
+mod std_io;
+mod stream;
+mod taip;
+mod talker;
+mod time;
+mod trace;
+mod ubx;
+mod validate;
+mod vhw;
+mod vtg;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "postcard")]
+mod wire;
+mod xte;
+mod zda;
+
+#[cfg(feature = "embedded-io-async")]
+pub use async_io::FixStream;
+pub use batch::{WindowSummary, extract_all_gga, extract_window};
+pub use buffer::NmeaBuffer;
+pub use checked::{GgaError, extract_gga_slice, try_extract_gga};
+pub use checksum::{checksum, finalize_sentence, verify_checksum};
+pub use compact::{decode_compact_block, extract_gga_compact};
+pub use cursor::{DmaCounter, DmaCursor};
+pub use dbt::{extract_dbt, is_dbt};
+pub use decode::{DecodedFix, decode_position_block};
+pub use degrees::{extract_gga_e7, minutes_e5_to_degrees_e7};
+pub use double::DoubleBuffer;
+pub use dpt::{extract_dpt, is_dpt};
+pub use dtm::{extract_dtm, is_dtm};
+pub use emit::{emit_gga, encode_gga, encode_rmc};
+pub use encoder::PositionEncoder;
+pub use extended::{EXTENDED_BLOCK_VERSION, ExtendedFix, decode_extended_block, extract_gga_extended};
+#[cfg(feature = "ffi")]
+pub use ffi::{gga_extract, gga_is_gga};
+pub use field::{FieldRef, Fields, extract_fields, field};
+pub use fix::{FixType, GgaFix, extract_gga_if, extract_gga_struct, gga_fix_type};
+pub use frame::{Framer, SentenceIter, find_sentence_start};
+pub use gbs::{extract_gbs, is_gbs};
+pub use gll::{extract_gll, is_gll};
+pub use gns::{extract_gns, is_gns};
+pub use golden::extract_gga_const;
+pub use grs::{extract_grs, is_grs};
+pub use gsa::{extract_gsa, is_gsa};
+pub use gst::{extract_gst, is_gst};
+pub use gsv::{extract_gsv, is_gsv};
+pub use hdt::{extract_hdt, is_hdt};
+#[cfg(feature = "embedded-io")]
+pub use io::GgaReader;
+#[cfg(feature = "mavlink")]
+pub use mavlink::{GLOBAL_POSITION_INT_LENGTH, GPS_RAW_INT_LENGTH, GlobalPositionInt, GpsRawInt};
+pub use mtw::{extract_mtw, is_mtw};
+pub use mwv::{extract_mwv, is_mwv};
+#[cfg(feature = "embedded-hal-nb")]
+pub use nb::NbGgaReader;
+#[cfg(feature = "profiling")]
+pub use profile::{ParseStats, extract_gga_profiled};
+#[cfg(feature = "heapless")]
+pub use queue::{FixConsumer, FixProducer, split_fix_queue};
+pub use rmb::{extract_rmb, is_rmb};
+pub use rmc::{extract_rmc, is_rmc};
+pub use rot::{extract_rot, is_rot};
+pub use scanner::{DmaEvent, SentenceScanner, Sentences};
+pub use sentence::{Sentence, SentenceType, classify, is_sentence};
+#[cfg(feature = "critical-section")]
+pub use shared::SharedScanner;
+#[cfg(feature = "simulator")]
+pub use simulator::{Simulator, SimulatorConfig};
+#[cfg(feature = "std")]
+pub use std_io::LogReader;
+pub use stream::{GgaStream, Reassembler};
+pub use taip::{TAIP_RPV_LENGTH, format_taip_rpv};
+pub use talker::{Talker, gga_talker, talker};
+pub use time::{PackedDate, PackedTime};
+pub use ubx::{NAV_PVT_LENGTH, extract_gga_nav_pvt};
+pub use validate::{
+    GGA_MIN_FIX_LENGTH, check_gga_layout, extract_gga_in_range, extract_gga_untorn, gga_length_ok,
+    gga_position_in_range, terminated_length,
+};
+pub use vhw::{VHW_MAGNETIC_HEADING, VHW_SPEED, VHW_TRUE_HEADING, extract_vhw, is_vhw};
+pub use vtg::{extract_vtg, is_vtg};
+#[cfg(feature = "wasm")]
+pub use wasm::{JsFix, js_extract_gga, js_is_gga};
+#[cfg(feature = "postcard")]
+pub use wire::{POSTCARD_FIX_MAX_LENGTH, encode_postcard, extract_gga_postcard};
+pub use xte::{extract_xte, is_xte};
+pub use zda::{extract_zda, is_zda};
+
+use parse::{
+    byte_at, digit_at, parse_decimal, parse_decimal_u64, parse_latitude, parse_longitude, parse_signed_decimal,
+    skip_field,
+};
+use trace::log_debug;
+
 /// Determine if the sentence is a GGA sentence.
 #[inline]
-pub fn is_gga(buffer: &[u8; 1024], sentence_begin: usize) -> bool {
-    unsafe {
-        *buffer.get_unchecked((sentence_begin + 3) & 1023) == b'G'
-            && *buffer.get_unchecked((sentence_begin + 4) & 1023) == b'G'
-            && *buffer.get_unchecked((sentence_begin + 5) & 1023) == b'A'
-    }
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+pub fn is_gga<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    buffer.at(sentence_begin + 3) == b'G'
+        && buffer.at(sentence_begin + 4) == b'G'
+        && buffer.at(sentence_begin + 5) == b'A'
 }
 
 #[inline]
@@ -21,105 +177,409 @@ pub fn calculate_sentence_length(ndtr: u16, sentence_begin: usize) -> usize {
     }
 }
 
-const POW10_10_DIGITS: [u32; 10] = [
-    1000000000, 100000000, 10000000, 1000000, 100000, 10000, 1000, 100, 10, 1,
-];
-
 /// Extract position data from a GGA (Global Positioning System Fix Data) sentence in a circular buffer.
 ///
 /// ### Arguments
-/// * `buffer` - A 1024-byte circular buffer containing NMEA 0183 data.
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
 /// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
 /// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
 ///
 /// ### Returns
-/// If the sentence contains a GNSS fix.
+/// If the sentence contains a GNSS fix. With the `strict` feature, also if every byte read is where the layout expects,
+/// as checked by [`try_extract_gga`].
 #[inline]
-pub fn extract_gga(buffer: &[u8; 1024], sentence_begin: usize, position_block: &mut [u8; 10]) -> bool {
-    unsafe {
-        // Check time field
-        if *buffer.get_unchecked((sentence_begin + 7) & 1023) == b',' {
-            // No time field, assume no fix
-            return false;
-        }
-
-        // Check latitude field
-        if *buffer.get_unchecked((sentence_begin + 18) & 1023) == b',' {
-            // No latitude field, no fix
-            return false;
-        }
-
-        // Parse latitude
-        let mut lat: u32 = 0;
-        lat += ((*buffer.get_unchecked((sentence_begin + 18) & 1023) - b'0') as u32) * POW10_10_DIGITS[0];
-        lat += ((*buffer.get_unchecked((sentence_begin + 19) & 1023) - b'0') as u32) * POW10_10_DIGITS[1];
-        lat += ((*buffer.get_unchecked((sentence_begin + 20) & 1023) - b'0') as u32) * POW10_10_DIGITS[2];
-        lat += ((*buffer.get_unchecked((sentence_begin + 21) & 1023) - b'0') as u32) * POW10_10_DIGITS[3];
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+pub fn extract_gga<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, position_block: &mut [u8; 10]) -> bool {
+    extract_gga_into(buffer, sentence_begin, position_block)
+}
+
+/// Extract position data from a GGA sentence in a circular buffer, passing it to an encoder.
+///
+/// Parses as [`extract_gga`], which is this function with the position block as the encoder, so the application's
+/// wire format can be written without an intermediate copy.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `encoder` - Receives the parsed position data, see [`PositionEncoder::encode`].
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix. The encoder is not called otherwise.
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+pub fn extract_gga_into<B: NmeaBuffer + ?Sized, E: PositionEncoder + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    encoder: &mut E,
+) -> bool {
+    if cfg!(feature = "strict") {
+        return match try_extract_gga(buffer, sentence_begin) {
+            Ok(fix) => {
+                encoder.encode(fix.lat, fix.lon, fix.flags, fix.hdop);
+                true
+            }
+            Err(error) => {
+                log_debug!("discarded GGA at {}: {:?}", sentence_begin, error);
+                false
+            }
+        };
+    }
+
+    // Check time field
+    if buffer.at(sentence_begin + 7) == b',' {
+        // No time field, assume no fix
+        log_debug!("discarded GGA at {}: no time", sentence_begin);
+        return false;
+    }
+
+    // Check latitude field
+    if buffer.at(sentence_begin + 18) == b',' {
+        // No latitude field, no fix
+        log_debug!("discarded GGA at {}: no fix", sentence_begin);
+        return false;
+    }
+
+    // Parse latitude
+    let lat = parse_latitude(buffer, sentence_begin + 18);
+
+    // Latitude hemisphere
+    let mut flags = ((buffer.at(sentence_begin + 29) == b'N') as u8) << 1;
+
+    // Parse longitude
+    let lon = parse_longitude(buffer, sentence_begin + 31);
+
+    // Longitude hemisphere
+    flags |= (buffer.at(sentence_begin + 43) == b'E') as u8;
+
+    // Parse hdop
+    // Wrapping arithmetic so a malformed field produces garbage rather than an overflow panic
+    let hdop = if buffer.at(sentence_begin + 51) == b'.' {
+        let mut hdop: u8 = 0;
+        // Integer part is single digit
+        hdop = hdop.wrapping_add(buffer.at(sentence_begin + 50).wrapping_sub(b'0').wrapping_mul(10));
         // Skip decimal point
-        lat += ((*buffer.get_unchecked((sentence_begin + 23) & 1023) - b'0') as u32) * POW10_10_DIGITS[4];
-        lat += ((*buffer.get_unchecked((sentence_begin + 24) & 1023) - b'0') as u32) * POW10_10_DIGITS[5];
-        lat += ((*buffer.get_unchecked((sentence_begin + 25) & 1023) - b'0') as u32) * POW10_10_DIGITS[6];
-        lat += ((*buffer.get_unchecked((sentence_begin + 26) & 1023) - b'0') as u32) * POW10_10_DIGITS[7];
-        lat += ((*buffer.get_unchecked((sentence_begin + 27) & 1023) - b'0') as u32) * POW10_10_DIGITS[8];
-
-        position_block[0] = (lat >> 24) as u8;
-        position_block[1] = (lat >> 16) as u8;
-        position_block[2] = (lat >> 8) as u8;
-        position_block[3] = lat as u8;
-
-        // Latitude hemisphere
-        position_block[8] = ((*buffer.get_unchecked((sentence_begin + 29) & 1023) == b'N') as u8) << 1;
-
-        // Parse longitude
-        let mut lon: u32 = 0;
-        lon += ((*buffer.get_unchecked((sentence_begin + 31) & 1023) - b'0') as u32) * POW10_10_DIGITS[0];
-        lon += ((*buffer.get_unchecked((sentence_begin + 32) & 1023) - b'0') as u32) * POW10_10_DIGITS[1];
-        lon += ((*buffer.get_unchecked((sentence_begin + 33) & 1023) - b'0') as u32) * POW10_10_DIGITS[2];
-        lon += ((*buffer.get_unchecked((sentence_begin + 34) & 1023) - b'0') as u32) * POW10_10_DIGITS[3];
-        lon += ((*buffer.get_unchecked((sentence_begin + 35) & 1023) - b'0') as u32) * POW10_10_DIGITS[4];
+        hdop = hdop.wrapping_add(buffer.at(sentence_begin + 52).wrapping_sub(b'0'));
+        hdop
+    } else {
+        const ASCII_OFFSET: u16 = b'0' as u16;
+        let mut hdop: u16 = 0;
+        // Integer part is double digit
+        hdop = hdop.wrapping_add((buffer.at(sentence_begin + 50) as u16).wrapping_sub(ASCII_OFFSET).wrapping_mul(100));
+        hdop = hdop.wrapping_add((buffer.at(sentence_begin + 51) as u16).wrapping_sub(ASCII_OFFSET).wrapping_mul(10));
         // Skip decimal point
-        lon += ((*buffer.get_unchecked((sentence_begin + 37) & 1023) - b'0') as u32) * POW10_10_DIGITS[5];
-        lon += ((*buffer.get_unchecked((sentence_begin + 38) & 1023) - b'0') as u32) * POW10_10_DIGITS[6];
-        lon += ((*buffer.get_unchecked((sentence_begin + 39) & 1023) - b'0') as u32) * POW10_10_DIGITS[7];
-        lon += ((*buffer.get_unchecked((sentence_begin + 40) & 1023) - b'0') as u32) * POW10_10_DIGITS[8];
-        lon += ((*buffer.get_unchecked((sentence_begin + 41) & 1023) - b'0') as u32) * POW10_10_DIGITS[9];
-
-        position_block[4] = (lon >> 24) as u8;
-        position_block[5] = (lon >> 16) as u8;
-        position_block[6] = (lon >> 8) as u8;
-        position_block[7] = lon as u8;
-
-        // Longitude hemisphere
-        position_block[8] |= (*buffer.get_unchecked((sentence_begin + 43) & 1023) == b'E') as u8;
-
-        // Parse hdop
-        if buffer[(sentence_begin + 51) & 1023] == b'.' {
-            let mut hdop: u8 = 0;
-            // Integer part is single digit
-            hdop += (*buffer.get_unchecked((sentence_begin + 50) & 1023) - b'0') * 10;
-            // Skip decimal point
-            hdop += *buffer.get_unchecked((sentence_begin + 52) & 1023) - b'0';
-            position_block[9] = hdop;
-        } else {
-            const ASCII_OFFSET: u16 = b'0' as u16;
-            let mut hdop: u16 = 0;
-            // Integer part is double digit
-            hdop += (*buffer.get_unchecked((sentence_begin + 50) & 1023) as u16 - ASCII_OFFSET) * 100;
-            hdop += (*buffer.get_unchecked((sentence_begin + 51) & 1023) as u16 - ASCII_OFFSET) * 10;
-            // Skip decimal point
-            hdop += *buffer.get_unchecked((sentence_begin + 53) & 1023) as u16 - ASCII_OFFSET;
-            position_block[9] = if hdop < 256 { hdop as u8 } else { 255 };
-        }
+        hdop = hdop.wrapping_add((buffer.at(sentence_begin + 53) as u16).wrapping_sub(ASCII_OFFSET));
+        if hdop < 256 { hdop as u8 } else { 255 }
+    };
+
+    encoder.encode(lat, lon, flags, hdop);
+    true
+}
+
+/// Extract position data and the fields following HDOP from a GGA sentence in a circular buffer.
+///
+/// The position block is filled as by [`extract_gga`]. The extension block layout is:
+/// * `[0..4]` - Altitude above mean sea level in decimetres, signed big-endian.
+/// * `[4..6]` - Geoid separation in decimetres, signed big-endian. Add to the altitude for the ellipsoidal height.
+/// * `[6]` - Fix quality: 0 = invalid, 1 = GPS, 2 = DGPS, 3 = PPS, 4 = RTK fixed, 5 = RTK float, 6 = dead reckoning,
+///   7 = manual input, 8 = simulation.
+/// * `[7]` - Number of satellites used in the fix.
+/// * `[8..10]` - Age of differential corrections in seconds, big-endian. `0xFFFF` if the field is empty.
+/// * `[10..12]` - Differential reference station ID, big-endian. `0xFFFF` if the field is empty.
+/// * `[12..16]` - UTC time of the fix in milliseconds since midnight, big-endian.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+/// * `extension_block` - Output buffer where the additional fields will be stored (16 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix. The extension block is left untouched otherwise.
+#[inline]
+pub fn extract_gga_ext<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 10],
+    extension_block: &mut [u8; 16],
+) -> bool {
+    if !extract_gga(buffer, sentence_begin, position_block) {
+        return false;
+    }
+
+    // Altitude follows the variable width hdop, integer part is 1 to 5 digits
+    let (altitude, end) = parse_signed_decimal(buffer, skip_field(buffer, sentence_begin + 50), 1);
+    extension_block[0] = (altitude >> 24) as u8;
+    extension_block[1] = (altitude >> 16) as u8;
+    extension_block[2] = (altitude >> 8) as u8;
+    extension_block[3] = altitude as u8;
+
+    // Skip altitude units
+    let (separation, end) = parse_signed_decimal(buffer, skip_field(buffer, skip_field(buffer, end)), 1);
+    let separation = separation.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    extension_block[4] = (separation >> 8) as u8;
+    extension_block[5] = separation as u8;
+
+    // Fix quality is a single digit
+    extension_block[6] = digit_at(buffer, sentence_begin + 45) as u8;
+
+    // Satellites used is always two digits
+    extension_block[7] = (digit_at(buffer, sentence_begin + 47) * 10 + digit_at(buffer, sentence_begin + 48)) as u8;
+
+    // Skip separation units, differential fields are empty without corrections
+    let age_begin = skip_field(buffer, skip_field(buffer, end));
+    let (age, end) = parse_decimal(buffer, age_begin, 0);
+    let age = if byte_at(buffer, age_begin) == b',' {
+        0xFFFF
+    } else if age < 65535 {
+        age as u16
+    } else {
+        65534
+    };
+    extension_block[8] = (age >> 8) as u8;
+    extension_block[9] = age as u8;
+
+    // Station ID is 0 to 4 digits and the last field
+    let station_begin = skip_field(buffer, end);
+    let (station, _) = parse_decimal(buffer, station_begin, 0);
+    let station = match byte_at(buffer, station_begin) {
+        b',' | b'*' => 0xFFFF,
+        _ => station as u16,
+    };
+    extension_block[10] = (station >> 8) as u8;
+    extension_block[11] = station as u8;
+
+    // Parse time, hhmmss.sss
+    let hours = digit_at(buffer, sentence_begin + 7) * 10 + digit_at(buffer, sentence_begin + 8);
+    let minutes = digit_at(buffer, sentence_begin + 9) * 10 + digit_at(buffer, sentence_begin + 10);
+    let (millis, _) = parse_decimal(buffer, sentence_begin + 11, 3);
+    // Saturates for malformed digits or an overlong seconds field
+    let time = (hours * 60 + minutes).saturating_mul(60_000).saturating_add(millis);
+    extension_block[12] = (time >> 24) as u8;
+    extension_block[13] = (time >> 16) as u8;
+    extension_block[14] = (time >> 8) as u8;
+    extension_block[15] = time as u8;
+
+    true
+}
+
+/// Extract position data from a GGA sentence in a circular buffer, tolerating variable field precision.
+///
+/// Unlike [`extract_gga`], which assumes the exact digit counts of `hhmmss.sss`, `ddmm.mmmmm` and `dddmm.mmmmm`,
+/// every field is scanned to the comma and scaled to the same encoding. Use this for receivers that output a
+/// different number of decimal places, e.g. 4 on the minutes.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix.
+#[inline]
+pub fn extract_gga_scan<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 10],
+) -> bool {
+    // Check time field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        // No time field, assume no fix
+        return false;
+    }
+
+    // Check latitude field
+    let lat_begin = skip_field(buffer, sentence_begin + 7);
+    if byte_at(buffer, lat_begin) == b',' {
+        // No latitude field, no fix
+        return false;
+    }
+
+    // Latitude minutes are scaled to 6 decimals and longitude minutes to 5, as in extract_gga
+    let (lat, end) = parse_decimal(buffer, lat_begin, 6);
+    let lat_hemisphere = skip_field(buffer, end);
+    let (lon, end) = parse_decimal(buffer, skip_field(buffer, lat_hemisphere), 5);
+    let lon_hemisphere = skip_field(buffer, end);
+
+    position_block[0] = (lat >> 24) as u8;
+    position_block[1] = (lat >> 16) as u8;
+    position_block[2] = (lat >> 8) as u8;
+    position_block[3] = lat as u8;
+    position_block[4] = (lon >> 24) as u8;
+    position_block[5] = (lon >> 16) as u8;
+    position_block[6] = (lon >> 8) as u8;
+    position_block[7] = lon as u8;
+    position_block[8] =
+        ((byte_at(buffer, lat_hemisphere) == b'N') as u8) << 1 | (byte_at(buffer, lon_hemisphere) == b'E') as u8;
+
+    // Skip fix quality and satellites used
+    let hdop_begin = skip_field(buffer, skip_field(buffer, skip_field(buffer, lon_hemisphere)));
+    let (hdop, _) = parse_decimal(buffer, hdop_begin, 1);
+    position_block[9] = if hdop < 256 { hdop as u8 } else { 255 };
+
+    true
+}
+
+/// Extract high-precision position data from a GGA sentence in a circular buffer.
+///
+/// Unlike [`extract_gga`], the latitude and longitude fields are scanned to the comma so receivers in high-precision
+/// mode with up to 7 decimal places on the minutes keep their full resolution.
+///
+/// The position block layout is:
+/// * `[0..8]` - Latitude as `ddmm.mmmmmmm` scaled by 10^7, big-endian.
+/// * `[8..16]` - Longitude as `dddmm.mmmmmmm` scaled by 10^7, big-endian.
+/// * `[16]` - Flags: bit 1 is set if north, bit 0 if east.
+/// * `[17]` - HDOP in tenths, saturating at 255.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (18 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix.
+#[inline]
+pub fn extract_gga_hp<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 18],
+) -> bool {
+    // Check time field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        // No time field, assume no fix
+        return false;
+    }
+
+    // Check latitude field, time precision varies in high-precision mode
+    let lat_begin = skip_field(buffer, sentence_begin + 7);
+    if byte_at(buffer, lat_begin) == b',' {
+        // No latitude field, no fix
+        return false;
+    }
+
+    let (lat, end) = parse_decimal_u64(buffer, lat_begin, 7);
+    let lat_hemisphere = skip_field(buffer, end);
+    let (lon, end) = parse_decimal_u64(buffer, skip_field(buffer, lat_hemisphere), 7);
+    let lon_hemisphere = skip_field(buffer, end);
+
+    position_block[..8].copy_from_slice(&lat.to_be_bytes());
+    position_block[8..16].copy_from_slice(&lon.to_be_bytes());
+    position_block[16] =
+        ((byte_at(buffer, lat_hemisphere) == b'N') as u8) << 1 | (byte_at(buffer, lon_hemisphere) == b'E') as u8;
+
+    // Skip fix quality and satellites used
+    let hdop_begin = skip_field(buffer, skip_field(buffer, skip_field(buffer, lon_hemisphere)));
+    let (hdop, _) = parse_decimal(buffer, hdop_begin, 1);
+    position_block[17] = if hdop < 256 { hdop as u8 } else { 255 };
+
+    true
+}
+
+/// Extract position data from a GGA sentence in a circular buffer, with HDOP in hundredths.
+///
+/// [`extract_gga`] saturates HDOP at 25.5. The position block layout is:
+/// * `[0..9]` - Latitude, longitude and flags as filled by [`extract_gga`].
+/// * `[9..11]` - HDOP in hundredths, big-endian, saturating at 655.35.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (11 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix.
+#[inline]
+pub fn extract_gga_wide<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 11],
+) -> bool {
+    let mut narrow_block = [0; 10];
+    if !extract_gga(buffer, sentence_begin, &mut narrow_block) {
+        return false;
     }
+    position_block[..9].copy_from_slice(&narrow_block[..9]);
+
+    let (hdop, _) = parse_decimal(buffer, sentence_begin + 50, 2);
+    let hdop = if hdop < 65536 { hdop as u16 } else { 65535 };
+    position_block[9] = (hdop >> 8) as u8;
+    position_block[10] = hdop as u8;
+
     true
 }
 
+/// Extract position data from a GGA sentence in a circular buffer, with little-endian latitude and longitude.
+///
+/// The position block layout matches [`extract_gga`] except for byte order:
+/// * `[0..4]` - Latitude as `ddmm.mmmmm` scaled by 10^6, little-endian.
+/// * `[4..8]` - Longitude as `dddmm.mmmmm` scaled by 10^5, little-endian.
+/// * `[8]` - Flags: bit 1 is set if north, bit 0 if east.
+/// * `[9]` - HDOP in tenths, saturating at 255.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix.
+#[inline]
+pub fn extract_gga_le<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 10],
+) -> bool {
+    if !extract_gga(buffer, sentence_begin, position_block) {
+        return false;
+    }
+    position_block[0..4].reverse();
+    position_block[4..8].reverse();
+    true
+}
+
+/// Extract position data from a GGA sentence in a circular buffer without data-dependent branches.
+///
+/// Produces the same position block as [`extract_gga`] for a sentence with a fix, for cores without branch
+/// prediction where the early returns cost more than parsing the fields regardless. The `strict` checks are not
+/// applied.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix. The position block is always written and holds meaningless data otherwise.
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic::no_panic)]
+pub fn extract_gga_branchless<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 10],
+) -> bool {
+    // Neither the time nor the latitude field is empty
+    let has_fix = (buffer.at(sentence_begin + 7) != b',') & (buffer.at(sentence_begin + 18) != b',');
+
+    let lat = parse_latitude(buffer, sentence_begin + 18);
+    let lon = parse_longitude(buffer, sentence_begin + 31);
+    let flags = ((buffer.at(sentence_begin + 29) == b'N') as u8) << 1 | (buffer.at(sentence_begin + 43) == b'E') as u8;
+
+    // Integer part is one digit, or two when the decimal point is not at 51
+    let wide = (buffer.at(sentence_begin + 51) != b'.') as u32;
+    let tens = digit_at(buffer, sentence_begin + 50).wrapping_mul(1 + 9 * wide);
+    let units = digit_at(buffer, sentence_begin + 51).wrapping_mul(wide);
+    let tenths = digit_at(buffer, sentence_begin + 52 + wide as usize);
+    let hdop = tens.wrapping_add(units).wrapping_mul(10).wrapping_add(tenths).min(255) as u8;
+
+    position_block.encode(lat, lon, flags, hdop);
+    has_fix
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::prelude::*;
 
-    fn shift_buffer(buffer: &mut [u8; 1024], sentence: &[u8], dest: usize) {
+    pub(crate) fn shift_buffer(buffer: &mut [u8; 1024], sentence: &[u8], dest: usize) {
         let mut i = dest;
         for &b in sentence {
             unsafe {
@@ -129,11 +589,21 @@ mod tests {
         }
     }
 
+    /// GGA sentences shared by the test modules.
+    pub(crate) const GGA_WITH_FIX: &[u8] =
+        b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n";
+
+    pub(crate) const GGA_NO_FIX: &[u8] = b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n";
+
+    /// Above the 42.95° latitude the position block wraps at.
+    pub(crate) const GGA_HIGH_LATITUDE: &[u8] =
+        b"$GNGGA,120000.000,5130.12345,N,00007.65432,W,1,09,0.9,35.0,M,47.0,M,,*4A\r\n";
+
     #[test]
     fn test_is_gga() {
         let mut buffer: [u8; 1024] = [0; 1024];
         for i in 0..1024 {
-            shift_buffer(&mut buffer, &GGA_WITH_TIME_WITH_FIX[0].0, i);
+            shift_buffer(&mut buffer, GGA_WITH_TIME_WITH_FIX[0].0, i);
             assert!(!is_gga(&buffer, (i.wrapping_sub(1)) & 1023));
             assert!(is_gga(&buffer, i));
             assert!(!is_gga(&buffer, (i + 1) & 1023));
@@ -193,6 +663,200 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ext_no_fix() {
+        let mut position_block = [0; 10];
+        let mut extension_block = [0; 16];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in [&GGA_NO_TIME_NO_FIX[..], &GGA_WITH_TIME_NO_FIX[..]] {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_ext(&buffer, i, &mut position_block, &mut extension_block);
+                assert!(!parsed);
+                assert_eq!(position_block, [0; 10]);
+                assert_eq!(extension_block, [0; 16]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ext_with_fix() {
+        let mut position_block = [0; 10];
+        let mut extension_block = [0; 16];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_position_block, expected_extension_block) in GGA_EXT_WITH_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_ext(&buffer, i, &mut position_block, &mut extension_block);
+                assert!(parsed);
+                assert_eq!(position_block, *expected_position_block);
+                assert_eq!(extension_block, *expected_extension_block);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ext_overlong_time() {
+        let mut position_block = [0; 10];
+        let mut extension_block = [0; 16];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in [
+            &b"$GNGGA,0512009999999999.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n"[..],
+            &b"$GNGGA,\xff\xff\xff\xff00.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n"[..],
+        ] {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                // Strict validation rejects the sentence before the time is read
+                if extract_gga_ext(&buffer, i, &mut position_block, &mut extension_block) {
+                    assert_eq!(extension_block[12..16], u32::MAX.to_be_bytes());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scan_no_fix() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in [&GGA_NO_TIME_NO_FIX[..], &GGA_WITH_TIME_NO_FIX[..]] {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_scan(&buffer, i, &mut position_block);
+                assert!(!parsed);
+                assert_eq!(position_block, [0; 10]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scan_with_fix() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_position_block) in GGA_WITH_TIME_WITH_FIX.iter().chain(GGA_SCAN_WITH_FIX.iter()) {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_scan(&buffer, i, &mut position_block);
+                assert!(parsed);
+                assert_eq!(position_block, *expected_position_block);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hp_no_fix() {
+        let mut position_block = [0; 18];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in [&GGA_NO_TIME_NO_FIX[..], &GGA_WITH_TIME_NO_FIX[..]] {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_hp(&buffer, i, &mut position_block);
+                assert!(!parsed);
+                assert_eq!(position_block, [0; 18]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hp_with_fix() {
+        let mut position_block = [0; 18];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_position_block) in GGA_HP_WITH_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_hp(&buffer, i, &mut position_block);
+                assert!(parsed);
+                assert_eq!(position_block, *expected_position_block);
+            }
+        }
+    }
+
+    #[test]
+    fn test_branchless_no_fix() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in [&GGA_NO_TIME_NO_FIX[..], &GGA_WITH_TIME_NO_FIX[..]] {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(!extract_gga_branchless(&buffer, i, &mut position_block));
+            }
+        }
+    }
+
+    #[test]
+    fn test_branchless_with_fix() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_position_block) in GGA_WITH_TIME_WITH_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(extract_gga_branchless(&buffer, i, &mut position_block));
+                assert_eq!(position_block, *expected_position_block);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wide_no_fix() {
+        let mut position_block = [0; 11];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, &GGA_WITH_TIME_NO_FIX, i);
+            let parsed = extract_gga_wide(&buffer, i, &mut position_block);
+            assert!(!parsed);
+            assert_eq!(position_block, [0; 11]);
+        }
+    }
+
+    #[test]
+    fn test_wide_with_fix() {
+        let mut position_block = [0; 11];
+        let mut narrow_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_hdop) in GGA_WIDE_WITH_FIX.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_wide(&buffer, i, &mut position_block);
+                assert!(parsed);
+                assert!(extract_gga(&buffer, i, &mut narrow_block));
+                assert_eq!(position_block[..9], narrow_block[..9]);
+                assert_eq!(
+                    u16::from_be_bytes([position_block[9], position_block[10]]),
+                    *expected_hdop
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_le_no_fix() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, &GGA_WITH_TIME_NO_FIX, i);
+            let parsed = extract_gga_le(&buffer, i, &mut position_block);
+            assert!(!parsed);
+            assert_eq!(position_block, [0; 10]);
+        }
+    }
+
+    #[test]
+    fn test_le_with_fix() {
+        let mut position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_position_block) in GGA_WITH_TIME_WITH_FIX.iter() {
+            let expected_lat = u32::from_be_bytes(expected_position_block[0..4].try_into().unwrap());
+            let expected_lon = u32::from_be_bytes(expected_position_block[4..8].try_into().unwrap());
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_gga_le(&buffer, i, &mut position_block);
+                assert!(parsed);
+                assert_eq!(position_block[0..4], expected_lat.to_le_bytes());
+                assert_eq!(position_block[4..8], expected_lon.to_le_bytes());
+                assert_eq!(position_block[8..10], expected_position_block[8..10]);
+            }
+        }
+    }
+
     const GGA_NO_TIME_NO_FIX: [u8; 32] = *b"$GNGGA,,,,,,0,00,25.5,,,,,,*64\r\n";
     const GGA_WITH_TIME_NO_FIX: [u8; 42] = *b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n";
 
@@ -218,4 +882,78 @@ mod tests {
             [215, 122, 90, 248, 37, 228, 101, 102, 0, 255],
         ),
     ];
+
+    const GGA_EXT_WITH_FIX: [(&[u8], [u8; 10], [u8; 16]); 4] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            [162, 248, 225, 210, 91, 54, 169, 63, 1, 28],
+            [0, 0, 4, 10, 1, 155, 1, 7, 255, 255, 255, 255, 1, 29, 168, 225],
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            [235, 28, 78, 124, 62, 87, 107, 238, 2, 21],
+            [255, 255, 255, 133, 254, 217, 2, 3, 0, 3, 0, 123, 3, 234, 132, 136],
+        ),
+        (
+            b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,4,12,39.9,12345.6,M,41.1,M,1.0,1023*55\r\n",
+            [215, 122, 90, 248, 37, 228, 101, 102, 0, 255],
+            [0, 1, 226, 64, 1, 155, 4, 12, 0, 1, 3, 255, 3, 234, 132, 136],
+        ),
+        (
+            b"$GNGGA,235959.999,3615.12012,S,06357.25158,W,5,35,0.6,0.0,M,0.0,M,,*7C\r\n",
+            [215, 122, 90, 248, 37, 228, 101, 102, 0, 6],
+            [0, 0, 0, 0, 0, 0, 5, 35, 255, 255, 255, 255, 5, 38, 91, 255],
+        ),
+    ];
+
+    const GGA_WIDE_WITH_FIX: [(&[u8], u16); 4] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            280,
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            210,
+        ),
+        // Beyond the 25.5 limit of the narrow block
+        (
+            b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,1,03,39.9,84.6,M,41.1,M,,*42\r\n",
+            3990,
+        ),
+        (
+            b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,1,03,99.99,84.6,M,41.1,M,,*71\r\n",
+            9999,
+        ),
+    ];
+
+    const GGA_HP_WITH_FIX: [(&[u8], [u8; 18]); 4] = [
+        (
+            b"$GNGGA,051200.00,2734.2197312,S,15303.0892745,E,4,12,0.6,103.4,M,41.1,M,1.0,0000*4A\r\n",
+            [0, 0, 0, 6, 93, 184, 210, 64, 0, 0, 0, 35, 161, 90, 28, 201, 1, 6],
+        ),
+        (
+            b"$GPGGA,181501.000,3944.500861,N,10459.166543,W,5,20,1.2,84.6,M,41.1,M,,*73\r\n",
+            [0, 0, 0, 9, 47, 27, 16, 226, 0, 0, 0, 24, 90, 38, 41, 22, 2, 12],
+        ),
+        (
+            b"$GNGGA,051200.993,8959.9999999,N,17959.9999999,W,4,12,12.5,103.4,M,41.1,M,,*69\r\n",
+            [0, 0, 0, 20, 220, 147, 127, 255, 0, 0, 0, 41, 208, 254, 131, 255, 2, 125],
+        ),
+        // Standard precision
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            [0, 0, 0, 6, 93, 184, 210, 52, 0, 0, 0, 35, 161, 90, 28, 156, 1, 28],
+        ),
+    ];
+
+    const GGA_SCAN_WITH_FIX: [(&[u8], [u8; 10]); 2] = [
+        (
+            b"$GNGGA,051200.00,2734.2197,S,15303.0893,E,1,07,2.8,103.4,M,41.1,M,,*6F\r\n",
+            [162, 248, 225, 180, 91, 54, 169, 66, 1, 28],
+        ),
+        (
+            b"$GPGGA,181501.0,3944.501,N,10459.167,W,1,03,2.10,84.6,M,41.1,M,,*4B\r\n",
+            [235, 28, 79, 8, 62, 87, 108, 28, 2, 21],
+        ),
+    ];
 }
@@ -0,0 +1,281 @@
+//! MAVLink `GPS_RAW_INT` and `GLOBAL_POSITION_INT` message fields from a GGA sentence.
+use crate::buffer::NmeaBuffer;
+use crate::degrees::extract_gga_e7;
+use crate::parse::{digit_at, parse_decimal, parse_signed_decimal, skip_field};
+
+/// MAVLink `GPS_FIX_TYPE` for a GGA fix quality.
+#[inline]
+const fn gps_fix_type(quality: u32) -> u8 {
+    match quality {
+        // DGPS
+        2 => 4,
+        // RTK fixed
+        4 => 6,
+        // RTK float
+        5 => 5,
+        // 3D fix, GGA does not distinguish 2D
+        _ => 3,
+    }
+}
+
+/// Fields of the MAVLink `GPS_RAW_INT` message (#24), without extensions.
+///
+/// Unknown values are set to the MAVLink "unknown" value, `u16::MAX`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GpsRawInt {
+    /// Timestamp in microseconds, left at 0 for the caller to set.
+    pub time_usec: u64,
+    /// Latitude in 10^-7 degrees.
+    pub lat: i32,
+    /// Longitude in 10^-7 degrees.
+    pub lon: i32,
+    /// Altitude above mean sea level in millimetres.
+    pub alt: i32,
+    /// HDOP in hundredths, saturating at 65534.
+    pub eph: u16,
+    /// VDOP, unknown.
+    pub epv: u16,
+    /// Ground speed, unknown.
+    pub vel: u16,
+    /// Course over ground, unknown.
+    pub cog: u16,
+    /// MAVLink `GPS_FIX_TYPE`.
+    pub fix_type: u8,
+    /// Number of satellites used in the fix.
+    pub satellites_visible: u8,
+}
+
+/// Length of the `GPS_RAW_INT` payload without extensions.
+pub const GPS_RAW_INT_LENGTH: usize = 30;
+
+impl GpsRawInt {
+    /// Extract the message fields from a GGA sentence in a circular buffer.
+    ///
+    /// ### Arguments
+    /// * `buffer` - A circular buffer containing NMEA 0183 data.
+    /// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+    ///
+    /// ### Returns
+    /// The fields, or `None` if the sentence does not contain a GNSS fix.
+    #[inline]
+    pub fn from_gga<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> Option<GpsRawInt> {
+        let mut position_block = [0; 9];
+        if !extract_gga_e7(buffer, sentence_begin, &mut position_block) {
+            return None;
+        }
+
+        // Hdop, then altitude in the next field
+        let (eph, end) = parse_decimal(buffer, sentence_begin + 50, 2);
+        let (alt, _) = parse_signed_decimal(buffer, skip_field(buffer, end), 1);
+
+        Some(GpsRawInt {
+            time_usec: 0,
+            lat: i32::from_be_bytes([
+                position_block[0],
+                position_block[1],
+                position_block[2],
+                position_block[3],
+            ]),
+            lon: i32::from_be_bytes([
+                position_block[4],
+                position_block[5],
+                position_block[6],
+                position_block[7],
+            ]),
+            alt: alt.saturating_mul(100),
+            eph: if eph < 65535 { eph as u16 } else { 65534 },
+            epv: u16::MAX,
+            vel: u16::MAX,
+            cog: u16::MAX,
+            fix_type: gps_fix_type(digit_at(buffer, sentence_begin + 45)),
+            satellites_visible: (digit_at(buffer, sentence_begin + 47) * 10 + digit_at(buffer, sentence_begin + 48))
+                as u8,
+        })
+    }
+
+    /// Serialize in MAVLink wire order, little-endian.
+    #[inline]
+    pub fn to_payload(&self) -> [u8; GPS_RAW_INT_LENGTH] {
+        let mut payload = [0; GPS_RAW_INT_LENGTH];
+        payload[0..8].copy_from_slice(&self.time_usec.to_le_bytes());
+        payload[8..12].copy_from_slice(&self.lat.to_le_bytes());
+        payload[12..16].copy_from_slice(&self.lon.to_le_bytes());
+        payload[16..20].copy_from_slice(&self.alt.to_le_bytes());
+        payload[20..22].copy_from_slice(&self.eph.to_le_bytes());
+        payload[22..24].copy_from_slice(&self.epv.to_le_bytes());
+        payload[24..26].copy_from_slice(&self.vel.to_le_bytes());
+        payload[26..28].copy_from_slice(&self.cog.to_le_bytes());
+        payload[28] = self.fix_type;
+        payload[29] = self.satellites_visible;
+        payload
+    }
+}
+
+/// Fields of the MAVLink `GLOBAL_POSITION_INT` message (#33).
+///
+/// GGA has no velocity, heading or home altitude, so those are 0 or the MAVLink "unknown" value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GlobalPositionInt {
+    /// Time since boot in milliseconds, left at 0 for the caller to set.
+    pub time_boot_ms: u32,
+    /// Latitude in 10^-7 degrees.
+    pub lat: i32,
+    /// Longitude in 10^-7 degrees.
+    pub lon: i32,
+    /// Altitude above mean sea level in millimetres.
+    pub alt: i32,
+    /// Altitude above home in millimetres, left at 0 for the caller to set.
+    pub relative_alt: i32,
+    /// Ground X speed, 0.
+    pub vx: i16,
+    /// Ground Y speed, 0.
+    pub vy: i16,
+    /// Ground Z speed, 0.
+    pub vz: i16,
+    /// Heading, unknown.
+    pub hdg: u16,
+}
+
+/// Length of the `GLOBAL_POSITION_INT` payload.
+pub const GLOBAL_POSITION_INT_LENGTH: usize = 28;
+
+impl GlobalPositionInt {
+    /// Extract the message fields from a GGA sentence in a circular buffer.
+    ///
+    /// ### Arguments
+    /// * `buffer` - A circular buffer containing NMEA 0183 data.
+    /// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+    ///
+    /// ### Returns
+    /// The fields, or `None` if the sentence does not contain a GNSS fix.
+    #[inline]
+    pub fn from_gga<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> Option<GlobalPositionInt> {
+        let gps_raw_int = GpsRawInt::from_gga(buffer, sentence_begin)?;
+        Some(GlobalPositionInt {
+            time_boot_ms: 0,
+            lat: gps_raw_int.lat,
+            lon: gps_raw_int.lon,
+            alt: gps_raw_int.alt,
+            relative_alt: 0,
+            vx: 0,
+            vy: 0,
+            vz: 0,
+            hdg: u16::MAX,
+        })
+    }
+
+    /// Serialize in MAVLink wire order, little-endian.
+    #[inline]
+    pub fn to_payload(&self) -> [u8; GLOBAL_POSITION_INT_LENGTH] {
+        let mut payload = [0; GLOBAL_POSITION_INT_LENGTH];
+        payload[0..4].copy_from_slice(&self.time_boot_ms.to_le_bytes());
+        payload[4..8].copy_from_slice(&self.lat.to_le_bytes());
+        payload[8..12].copy_from_slice(&self.lon.to_le_bytes());
+        payload[12..16].copy_from_slice(&self.alt.to_le_bytes());
+        payload[16..20].copy_from_slice(&self.relative_alt.to_le_bytes());
+        payload[20..22].copy_from_slice(&self.vx.to_le_bytes());
+        payload[22..24].copy_from_slice(&self.vy.to_le_bytes());
+        payload[24..26].copy_from_slice(&self.vz.to_le_bytes());
+        payload[26..28].copy_from_slice(&self.hdg.to_le_bytes());
+        payload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_NO_FIX, shift_buffer};
+
+    #[test]
+    fn test_gps_raw_int() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected) in GPS_RAW_INT.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(GpsRawInt::from_gga(&buffer, i), Some(*expected));
+            }
+        }
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert_eq!(GpsRawInt::from_gga(&buffer, i), None);
+            assert_eq!(GlobalPositionInt::from_gga(&buffer, i), None);
+        }
+    }
+
+    #[test]
+    fn test_global_position_int() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GPS_RAW_INT[1].0, 1000);
+        let global_position_int = GlobalPositionInt::from_gga(&buffer, 1000).unwrap();
+        assert_eq!(global_position_int.lat, 397416810);
+        assert_eq!(global_position_int.lon, -1049861090);
+        assert_eq!(global_position_int.alt, -12300);
+        assert_eq!(global_position_int.hdg, u16::MAX);
+        assert_eq!(
+            global_position_int.to_payload(),
+            [
+                0, 0, 0, 0, 106, 25, 176, 23, 30, 100, 108, 193, 244, 207, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255,
+                255
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gps_raw_int_payload() {
+        assert_eq!(
+            GPS_RAW_INT[0].1.to_payload(),
+            [
+                0, 0, 0, 0, 0, 0, 0, 0, 8, 26, 145, 239, 190, 205, 57, 91, 232, 147, 1, 0, 24, 1, 255, 255, 255, 255,
+                255, 255, 3, 7
+            ]
+        );
+    }
+
+    const GPS_RAW_INT: [(&[u8], GpsRawInt); 3] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            GpsRawInt {
+                time_usec: 0,
+                lat: -275703288,
+                lon: 1530514878,
+                alt: 103400,
+                eph: 280,
+                epv: u16::MAX,
+                vel: u16::MAX,
+                cog: u16::MAX,
+                fix_type: 3,
+                satellites_visible: 7,
+            },
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            GpsRawInt {
+                time_usec: 0,
+                lat: 397416810,
+                lon: -1049861090,
+                alt: -12300,
+                eph: 210,
+                epv: u16::MAX,
+                vel: u16::MAX,
+                cog: u16::MAX,
+                fix_type: 4,
+                satellites_visible: 3,
+            },
+        ),
+        (
+            b"$GNGGA,181501.000,6015.12012,N,06357.25158,W,4,12,39.9,12345.6,M,41.1,M,1.0,1023*4B\r\n",
+            GpsRawInt {
+                time_usec: 0,
+                lat: 602520020,
+                lon: -639541930,
+                alt: 12345600,
+                eph: 3990,
+                epv: u16::MAX,
+                vel: u16::MAX,
+                cog: u16::MAX,
+                fix_type: 6,
+                satellites_visible: 12,
+            },
+        ),
+    ];
+}
@@ -0,0 +1,86 @@
+//! Detect and parse MTW (Mean Temperature of Water) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_signed_decimal};
+
+/// Determine if the sentence is a MTW sentence.
+#[inline]
+pub fn is_mtw<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'M'
+        && byte_at(buffer, sentence_begin + 4) == b'T'
+        && byte_at(buffer, sentence_begin + 5) == b'W'
+}
+
+/// Extract the water temperature from a MTW sentence in a circular buffer.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the MTW sentence in the buffer.
+/// * `temperature_block` - Output buffer where the temperature in tenths of a degree Celsius will be stored, signed
+///   big-endian (2 bytes).
+///
+/// ### Returns
+/// If the sentence contains a temperature.
+#[inline]
+pub fn extract_mtw<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, temperature_block: &mut [u8; 2]) -> bool {
+    // Check temperature field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        return false;
+    }
+
+    let (temperature, _) = parse_signed_decimal(buffer, sentence_begin + 7, 1);
+    let temperature = temperature.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    temperature_block[0] = (temperature >> 8) as u8;
+    temperature_block[1] = temperature as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_mtw() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, MTW_VALID[0].0, i);
+            assert!(!is_mtw(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_mtw(&buffer, i));
+            assert!(!is_mtw(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_mtw_empty() {
+        let mut temperature_block = [0; 2];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, MTW_EMPTY, i);
+            let parsed = extract_mtw(&buffer, i, &mut temperature_block);
+            assert!(!parsed);
+            assert_eq!(temperature_block, [0; 2]);
+        }
+    }
+
+    #[test]
+    fn test_mtw_valid() {
+        let mut temperature_block = [0; 2];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_temperature_block) in MTW_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_mtw(&buffer, i, &mut temperature_block);
+                assert!(parsed);
+                assert_eq!(temperature_block, *expected_temperature_block);
+            }
+        }
+    }
+
+    const MTW_EMPTY: &[u8] = b"$YXMTW,,C*0C\r\n";
+
+    const MTW_VALID: [(&[u8], [u8; 2]); 2] = [
+        (b"$YXMTW,17.5,C*11\r\n", [0, 175]),
+        (b"$YXMTW,-1.25,C*39\r\n", [255, 244]),
+    ];
+}
@@ -0,0 +1,108 @@
+//! Detect and parse MWV (Wind Speed and Angle) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a MWV sentence.
+#[inline]
+pub fn is_mwv<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'M'
+        && byte_at(buffer, sentence_begin + 4) == b'W'
+        && byte_at(buffer, sentence_begin + 5) == b'V'
+}
+
+/// Extract wind angle and speed from a MWV sentence in a circular buffer.
+///
+/// The wind block layout is:
+/// * `[0..2]` - Wind angle in tenths of a degree, big-endian.
+/// * `[2..4]` - Wind speed in hundredths of the speed unit, big-endian.
+/// * `[4]` - Flags: bit 3 is set if the status is valid (`A`), bits 1-2 are the speed unit (0 = km/h, 1 = m/s,
+///   2 = knots, 3 = mph) and bit 0 is set if the reference is true (`T`) rather than relative (`R`).
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the MWV sentence in the buffer.
+/// * `wind_block` - Output buffer where parsed data will be stored (5 bytes).
+///
+/// ### Returns
+/// If the sentence contains a wind angle and the status is valid.
+#[inline]
+pub fn extract_mwv<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, wind_block: &mut [u8; 5]) -> bool {
+    // Check angle field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        return false;
+    }
+
+    let (angle, end) = parse_decimal(buffer, sentence_begin + 7, 1);
+    wind_block[0] = (angle >> 8) as u8;
+    wind_block[1] = angle as u8;
+
+    let reference = skip_field(buffer, end);
+    let (speed, end) = parse_decimal(buffer, skip_field(buffer, reference), 2);
+    let speed = if speed < 65536 { speed as u16 } else { 65535 };
+    wind_block[2] = (speed >> 8) as u8;
+    wind_block[3] = speed as u8;
+
+    let unit = skip_field(buffer, end);
+    let unit_code = match byte_at(buffer, unit) {
+        b'M' => 1,
+        b'N' => 2,
+        b'S' => 3,
+        _ => 0,
+    };
+    let valid = byte_at(buffer, skip_field(buffer, unit)) == b'A';
+    wind_block[4] = ((valid as u8) << 3) | (unit_code << 1) | (byte_at(buffer, reference) == b'T') as u8;
+
+    valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_mwv() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, MWV[0].0, i);
+            assert!(!is_mwv(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_mwv(&buffer, i));
+            assert!(!is_mwv(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_mwv_empty() {
+        let mut wind_block = [0; 5];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, MWV_EMPTY, i);
+            let parsed = extract_mwv(&buffer, i, &mut wind_block);
+            assert!(!parsed);
+            assert_eq!(wind_block, [0; 5]);
+        }
+    }
+
+    #[test]
+    fn test_mwv() {
+        let mut wind_block = [0; 5];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_wind_block, expected_valid) in MWV.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_mwv(&buffer, i, &mut wind_block);
+                assert_eq!(parsed, *expected_valid);
+                assert_eq!(wind_block, *expected_wind_block);
+            }
+        }
+    }
+
+    const MWV_EMPTY: &[u8] = b"$WIMWV,,R,,N,V*34\r\n";
+
+    const MWV: [(&[u8], [u8; 5], bool); 4] = [
+        (b"$WIMWV,045.5,R,12.3,N,A*17\r\n", [1, 199, 4, 206, 12], true),
+        (b"$WIMWV,270,T,5.25,M,A*0F\r\n", [10, 140, 2, 13, 11], true),
+        (b"$IIMWV,359.9,T,40.0,K,V*1B\r\n", [14, 15, 15, 160, 1], false),
+        (b"$WIMWV,10.0,R,3.0,S,A*0C\r\n", [0, 100, 1, 44, 14], true),
+    ];
+}
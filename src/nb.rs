@@ -0,0 +1,116 @@
+//! Non-blocking GGA fixes from serial drivers implementing `embedded_hal_nb::serial::Read`.
+use crate::fix::GgaFix;
+use crate::stream::GgaStream;
+use embedded_hal_nb::nb;
+use embedded_hal_nb::serial::Read;
+
+/// Reads bytes from an `embedded_hal_nb::serial::Read` implementor and yields the fixes of GGA sentences.
+///
+/// Call [`NbGgaReader::read_fix`] from a superloop, or wrap it in `nb::block!`.
+#[derive(Debug)]
+pub struct NbGgaReader<S> {
+    serial: S,
+    stream: GgaStream,
+}
+
+impl<S: Read<u8>> NbGgaReader<S> {
+    /// Create an adapter waiting for the start of a sentence.
+    #[inline]
+    pub const fn new(serial: S) -> NbGgaReader<S> {
+        NbGgaReader {
+            serial,
+            stream: GgaStream::new(),
+        }
+    }
+
+    /// Read every received byte until a GGA sentence containing a fix is complete.
+    ///
+    /// ### Returns
+    /// The fix, `WouldBlock` once no more bytes are available, or the serial error. A partly received sentence is
+    /// kept across calls.
+    #[inline]
+    pub fn read_fix(&mut self) -> nb::Result<GgaFix, S::Error> {
+        loop {
+            let byte = self.serial.read()?;
+            if let Some(fix) = self.stream.push(byte) {
+                return Ok(fix);
+            }
+        }
+    }
+
+    /// Give back the serial driver.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.serial
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_gga_struct;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+    use embedded_hal_nb::serial::{ErrorKind, ErrorType};
+
+    /// Serial driver with `available` bytes received, failing with an overrun at the end.
+    struct Uart<'a> {
+        bytes: &'a [u8],
+        available: usize,
+    }
+
+    impl ErrorType for Uart<'_> {
+        type Error = ErrorKind;
+    }
+
+    impl Read<u8> for Uart<'_> {
+        fn read(&mut self) -> nb::Result<u8, ErrorKind> {
+            let (&byte, rest) = self.bytes.split_first().ok_or(nb::Error::Other(ErrorKind::Overrun))?;
+            if self.available == 0 {
+                return Err(nb::Error::WouldBlock);
+            }
+            self.available -= 1;
+            self.bytes = rest;
+            Ok(byte)
+        }
+    }
+
+    #[test]
+    fn test_nb_gga_reader() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_WITH_FIX, 0);
+        let expected_fix = extract_gga_struct(&buffer, 0).unwrap();
+
+        let mut reader = NbGgaReader::new(Uart {
+            bytes: STREAM,
+            available: 0,
+        });
+        let mut fixes = 0;
+        let mut polls = 0;
+        loop {
+            match reader.read_fix() {
+                Ok(fix) => {
+                    assert_eq!(fix, expected_fix);
+                    fixes += 1;
+                }
+                Err(nb::Error::WouldBlock) => {
+                    // Bytes trickle in between polls
+                    reader.serial.available = 7;
+                    polls += 1;
+                }
+                Err(nb::Error::Other(error)) => {
+                    assert_eq!(error, ErrorKind::Overrun);
+                    break;
+                }
+            }
+        }
+        assert_eq!(fixes, 2);
+        assert_eq!(polls, STREAM.len().div_ceil(7));
+        assert!(reader.into_inner().bytes.is_empty());
+    }
+
+    const STREAM: &[u8] = b"73,M,41.1,M,,*59\r\n\
+        $GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGGA,0512$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n";
+}
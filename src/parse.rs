@@ -0,0 +1,238 @@
+//! Wrap-aware field parsing helpers shared by the sentence extractors.
+use crate::buffer::NmeaBuffer;
+
+/// Maximum length of a NMEA 0183 sentence including `$` and `\r\n`.
+pub(crate) const MAX_SENTENCE_LENGTH: usize = 82;
+
+const POW10_10_DIGITS: [u32; 10] = [
+    1000000000, 100000000, 10000000, 1000000, 100000, 10000, 1000, 100, 10, 1,
+];
+
+/// Read a byte from the circular buffer.
+#[inline(always)]
+pub(crate) fn byte_at<B: NmeaBuffer + ?Sized>(buffer: &B, index: usize) -> u8 {
+    buffer.at(index)
+}
+
+/// Read an ASCII digit from the circular buffer.
+#[inline(always)]
+pub(crate) fn digit_at<B: NmeaBuffer + ?Sized>(buffer: &B, index: usize) -> u32 {
+    byte_at(buffer, index).wrapping_sub(b'0') as u32
+}
+
+/// Pack eight bytes of the circular buffer into a word, the byte at `index + offsets[0]` in the lowest lane.
+#[inline(always)]
+fn load_lanes<B: NmeaBuffer + ?Sized>(buffer: &B, index: usize, offsets: [usize; 8]) -> u64 {
+    u64::from_le_bytes([
+        byte_at(buffer, index + offsets[0]),
+        byte_at(buffer, index + offsets[1]),
+        byte_at(buffer, index + offsets[2]),
+        byte_at(buffer, index + offsets[3]),
+        byte_at(buffer, index + offsets[4]),
+        byte_at(buffer, index + offsets[5]),
+        byte_at(buffer, index + offsets[6]),
+        byte_at(buffer, index + offsets[7]),
+    ])
+}
+
+/// Pack the first eight digits of a field with a decimal point at offset `point` into a word, first digit lowest.
+///
+/// When the field does not straddle the wrap point of a contiguous buffer, reads two overlapping unaligned words and
+/// merges them to drop the point. Otherwise falls back to reading byte by byte with wrapped indices.
+#[inline(always)]
+fn load_field<B: NmeaBuffer + ?Sized>(buffer: &B, index: usize, point: usize) -> u64 {
+    let field = buffer
+        .contiguous()
+        .and_then(|slice| slice.get(index.checked_rem(slice.len())?..))
+        .and_then(|rest| rest.first_chunk::<9>());
+    if let Some(&[b0, b1, b2, b3, b4, b5, b6, b7, b8]) = field {
+        let low = u64::from_le_bytes([b0, b1, b2, b3, b4, b5, b6, b7]);
+        let high = u64::from_le_bytes([b1, b2, b3, b4, b5, b6, b7, b8]);
+        // Lanes below the point from the first word, the rest shifted down a byte over it from the second
+        let mask = (1u64 << (8 * point)) - 1;
+        return (low & mask) | (high & !mask);
+    }
+    let mut offsets = [0; 8];
+    for (lane, offset) in offsets.iter_mut().enumerate() {
+        *offset = lane + (lane >= point) as usize;
+    }
+    load_lanes(buffer, index, offsets)
+}
+
+/// Convert eight ASCII digits packed first digit lowest into their 8-digit integer value.
+///
+/// Combines adjacent lanes pairwise in three multiply-add steps instead of eight dependent ones.
+///
+/// The `simd` feature replaces this with a vector version on x86_64 and aarch64.
+#[cfg_attr(feature = "simd", allow(dead_code))]
+#[inline(always)]
+pub(crate) const fn parse_8_digits(lanes: u64) -> u32 {
+    // Subtract `'0'` from every lane
+    let digits = lanes.wrapping_sub(0x3030_3030_3030_3030);
+    // Each even lane now holds a 2-digit value
+    let pairs = digits.wrapping_mul(10).wrapping_add(digits >> 8);
+    // Combine pairs into the two 4-digit halves and those into the 8-digit value
+    let quads = (pairs & 0x0000_00FF_0000_00FF).wrapping_mul(100 + (1_000_000 << 32));
+    let quads = quads.wrapping_add(((pairs >> 16) & 0x0000_00FF_0000_00FF).wrapping_mul(1 + (10_000 << 32)));
+    (quads >> 32) as u32
+}
+
+#[cfg(not(feature = "simd"))]
+use self::parse_8_digits as parse_lanes;
+#[cfg(feature = "simd")]
+use crate::simd::parse_8_digits as parse_lanes;
+
+/// Parse a `ddmm.mmmmm` latitude field starting at `index` into a 9-digit integer.
+#[inline(always)]
+pub(crate) fn parse_latitude<B: NmeaBuffer + ?Sized>(buffer: &B, index: usize) -> u32 {
+    // Skip decimal point
+    let high = parse_lanes(load_field(buffer, index, 4));
+    let lat = high.wrapping_mul(10).wrapping_add(digit_at(buffer, index + 9));
+    lat.wrapping_mul(POW10_10_DIGITS[8])
+}
+
+/// Parse a `dddmm.mmmmm` longitude field starting at `index` into a 10-digit integer.
+#[inline(always)]
+pub(crate) fn parse_longitude<B: NmeaBuffer + ?Sized>(buffer: &B, index: usize) -> u32 {
+    // Skip decimal point
+    let high = parse_lanes(load_field(buffer, index, 5));
+    let low = digit_at(buffer, index + 9) * 10 + digit_at(buffer, index + 10);
+    high.wrapping_mul(100).wrapping_add(low)
+}
+
+/// Parse a variable-width decimal field starting at `index`, scaled to `decimals` fractional digits.
+///
+/// Extra fractional digits are truncated and missing ones are zero-filled. An empty field parses as 0.
+///
+/// ### Returns
+/// The scaled value and the index of the byte that terminated the field.
+#[inline]
+pub(crate) fn parse_decimal<B: NmeaBuffer + ?Sized>(buffer: &B, mut index: usize, decimals: u32) -> (u32, usize) {
+    let mut value: u32 = 0;
+    let mut fraction_digits: u32 = 0;
+    let mut in_fraction = false;
+    for _ in 0..MAX_SENTENCE_LENGTH {
+        let b = byte_at(buffer, index);
+        if b.is_ascii_digit() {
+            if !in_fraction || fraction_digits < decimals {
+                value = value.saturating_mul(10).saturating_add((b - b'0') as u32);
+                fraction_digits += in_fraction as u32;
+            }
+        } else if b == b'.' && !in_fraction {
+            in_fraction = true;
+        } else {
+            break;
+        }
+        index += 1;
+    }
+    while fraction_digits < decimals {
+        value = value.saturating_mul(10);
+        fraction_digits += 1;
+    }
+    (value, index)
+}
+
+/// Parse a variable-width decimal field starting at `index` into a u64, scaled to `decimals` fractional digits.
+///
+/// ### Returns
+/// The scaled value and the index of the byte that terminated the field.
+#[inline]
+pub(crate) fn parse_decimal_u64<B: NmeaBuffer + ?Sized>(buffer: &B, mut index: usize, decimals: u32) -> (u64, usize) {
+    let mut value: u64 = 0;
+    let mut fraction_digits: u32 = 0;
+    let mut in_fraction = false;
+    for _ in 0..MAX_SENTENCE_LENGTH {
+        let b = byte_at(buffer, index);
+        if b.is_ascii_digit() {
+            if !in_fraction || fraction_digits < decimals {
+                value = value.saturating_mul(10).saturating_add((b - b'0') as u64);
+                fraction_digits += in_fraction as u32;
+            }
+        } else if b == b'.' && !in_fraction {
+            in_fraction = true;
+        } else {
+            break;
+        }
+        index += 1;
+    }
+    while fraction_digits < decimals {
+        value = value.saturating_mul(10);
+        fraction_digits += 1;
+    }
+    (value, index)
+}
+
+/// Parse a variable-width decimal field with an optional leading sign, scaled to `decimals` fractional digits.
+///
+/// ### Returns
+/// The scaled value and the index of the byte that terminated the field.
+#[inline]
+pub(crate) fn parse_signed_decimal<B: NmeaBuffer + ?Sized>(buffer: &B, index: usize, decimals: u32) -> (i32, usize) {
+    let sign = byte_at(buffer, index);
+    let negative = sign == b'-';
+    let (value, end) = parse_decimal(buffer, index + (negative || sign == b'+') as usize, decimals);
+    let value = if value <= i32::MAX as u32 {
+        value as i32
+    } else {
+        i32::MAX
+    };
+    (if negative { -value } else { value }, end)
+}
+
+/// Return the index just past the next `,` at or after `index`.
+///
+/// Stops at the `*` checksum delimiter so skipping past the last field is idempotent.
+#[inline]
+pub(crate) fn skip_field<B: NmeaBuffer + ?Sized>(buffer: &B, mut index: usize) -> usize {
+    for _ in 0..MAX_SENTENCE_LENGTH {
+        match byte_at(buffer, index) {
+            b',' => return index + 1,
+            b'*' => return index,
+            _ => index += 1,
+        }
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    /// Reference digit-at-a-time parse of the digits at `offsets` from `index`.
+    fn parse_digits(buffer: &[u8], index: usize, offsets: &[usize]) -> u32 {
+        offsets.iter().fold(0u32, |value, offset| {
+            value.wrapping_mul(10).wrapping_add(digit_at(buffer, index + offset))
+        })
+    }
+
+    #[test]
+    fn test_parse_8_digits() {
+        assert_eq!(parse_8_digits(u64::from_le_bytes(*b"00000000")), 0);
+        assert_eq!(parse_8_digits(u64::from_le_bytes(*b"12345678")), 12345678);
+        assert_eq!(parse_8_digits(u64::from_le_bytes(*b"99999999")), 99999999);
+        assert_eq!(parse_8_digits(u64::from_le_bytes(*b"00000001")), 1);
+        assert_eq!(parse_8_digits(u64::from_le_bytes(*b"10000000")), 10000000);
+    }
+
+    #[test]
+    fn test_parse_latitude_longitude() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            for field in LATITUDES {
+                shift_buffer(&mut buffer, field, i);
+                let lat = parse_digits(&buffer, i, &[0, 1, 2, 3, 5, 6, 7, 8, 9]).wrapping_mul(10);
+                assert_eq!(parse_latitude(&buffer, i), lat);
+            }
+            for field in LONGITUDES {
+                shift_buffer(&mut buffer, field, i);
+                let lon = parse_digits(&buffer, i, &[0, 1, 2, 3, 4, 6, 7, 8, 9, 10]);
+                assert_eq!(parse_longitude(&buffer, i), lon);
+            }
+        }
+    }
+
+    const LATITUDES: [&[u8]; 4] = [b"2734.21973", b"0000.00000", b"4259.99999", b"8959.99999"];
+
+    const LONGITUDES: [&[u8]; 3] = [b"15303.08927", b"00000.00000", b"18000.00000"];
+}
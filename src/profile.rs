@@ -0,0 +1,136 @@
+//! Cycle-count instrumentation of extraction, for spotting parse cost regressions on target.
+use crate::buffer::NmeaBuffer;
+use crate::extract_gga;
+
+/// Minimum, maximum and average cycle counts of the extractions recorded so far.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParseStats {
+    count: u32,
+    min: u32,
+    max: u32,
+    total: u64,
+}
+
+impl ParseStats {
+    /// Statistics with nothing recorded.
+    #[inline]
+    pub const fn new() -> ParseStats {
+        ParseStats {
+            count: 0,
+            min: u32::MAX,
+            max: 0,
+            total: 0,
+        }
+    }
+
+    /// Add the cycle count of one extraction.
+    #[inline]
+    pub fn record(&mut self, cycles: u32) {
+        self.count = self.count.saturating_add(1);
+        self.min = self.min.min(cycles);
+        self.max = self.max.max(cycles);
+        self.total = self.total.saturating_add(cycles as u64);
+    }
+
+    /// Number of extractions recorded.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Fewest cycles taken by an extraction, or `None` if nothing was recorded.
+    #[inline]
+    pub fn min(&self) -> Option<u32> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Most cycles taken by an extraction, or `None` if nothing was recorded.
+    #[inline]
+    pub fn max(&self) -> Option<u32> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Average cycles per extraction, rounded down, or `None` if nothing was recorded.
+    #[inline]
+    pub fn average(&self) -> Option<u32> {
+        (self.count > 0).then(|| (self.total / self.count as u64) as u32)
+    }
+
+    /// Forget everything recorded.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = ParseStats::new();
+    }
+}
+
+impl Default for ParseStats {
+    #[inline]
+    fn default() -> ParseStats {
+        ParseStats::new()
+    }
+}
+
+/// Extract position data from a GGA sentence as [`extract_gga`], recording how many cycles it took.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+/// * `cycles` - Reads a free-running cycle counter such as DWT CYCCNT on Cortex-M. It may wrap.
+/// * `stats` - Accumulates the cycle count of this extraction.
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix.
+#[inline]
+pub fn extract_gga_profiled<B: NmeaBuffer + ?Sized, C: FnMut() -> u32>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 10],
+    mut cycles: C,
+    stats: &mut ParseStats,
+) -> bool {
+    let start = cycles();
+    let has_fix = extract_gga(buffer, sentence_begin, position_block);
+    stats.record(cycles().wrapping_sub(start));
+    has_fix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_extract_gga_profiled() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut position_block = [0; 10];
+        let mut stats = ParseStats::new();
+        assert_eq!((stats.min(), stats.max(), stats.average()), (None, None, None));
+
+        // A counter that advances by 100 to 102 per read and wraps during the first extraction
+        let mut counter = u32::MAX - 50;
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            let cycles = || {
+                counter = counter.wrapping_add(100 + i as u32 % 3);
+                counter
+            };
+            assert!(extract_gga_profiled(
+                &buffer,
+                i,
+                &mut position_block,
+                cycles,
+                &mut stats
+            ));
+            assert_eq!(position_block, [162, 248, 225, 210, 91, 54, 169, 63, 1, 28]);
+        }
+        assert_eq!(stats.count(), 1024);
+        assert_eq!(stats.min(), Some(100));
+        assert_eq!(stats.max(), Some(102));
+        assert_eq!(stats.average(), Some(100));
+
+        stats.reset();
+        assert_eq!(stats, ParseStats::default());
+    }
+}
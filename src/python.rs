@@ -0,0 +1,136 @@
+//! Python bindings for replaying raw UART dumps through the production parser on the host.
+//!
+//! Build as a `cdylib`, e.g. with maturin, to import the `gga_extract` module.
+use crate::decode::{DecodedFix, decode_position_block};
+use crate::degrees::extract_gga_e7;
+use crate::extended::{ExtendedFix, decode_extended_block, extract_gga_extended};
+use crate::frame::Framer;
+use crate::{extract_gga, is_gga};
+use pyo3::prelude::*;
+use std::vec::Vec;
+
+/// Determine if the sentence at `sentence_begin` in a circular buffer is a GGA sentence.
+#[pyfunction(name = "is_gga")]
+fn py_is_gga(buffer: &[u8], sentence_begin: usize) -> bool {
+    !buffer.is_empty() && is_gga(buffer, sentence_begin)
+}
+
+/// Extract the 10-byte position block from a GGA sentence in a circular buffer, or `None` without a fix.
+#[pyfunction(name = "extract_gga")]
+fn py_extract_gga(buffer: &[u8], sentence_begin: usize) -> Option<[u8; 10]> {
+    let mut position_block = [0; 10];
+    (!buffer.is_empty() && extract_gga(buffer, sentence_begin, &mut position_block)).then_some(position_block)
+}
+
+/// Extract the 16-byte extended block from a GGA sentence in a circular buffer, or `None` without a fix.
+#[pyfunction(name = "extract_gga_extended")]
+fn py_extract_gga_extended(buffer: &[u8], sentence_begin: usize) -> Option<[u8; 16]> {
+    let mut extended_block = [0; 16];
+    (!buffer.is_empty() && extract_gga_extended(buffer, sentence_begin, &mut extended_block)).then_some(extended_block)
+}
+
+/// Extract the 9-byte signed 10^-7 degree position block from a GGA sentence in a circular buffer, or `None` without
+/// a fix. Unlike the `extract_gga` block, it does not wrap at high latitudes.
+#[pyfunction(name = "extract_gga_e7")]
+fn py_extract_gga_e7(buffer: &[u8], sentence_begin: usize) -> Option<[u8; 9]> {
+    let mut position_block = [0; 9];
+    (!buffer.is_empty() && extract_gga_e7(buffer, sentence_begin, &mut position_block)).then_some(position_block)
+}
+
+/// Decode a position block from `extract_gga`.
+#[pyfunction(name = "decode_position_block")]
+fn py_decode_position_block(position_block: [u8; 10]) -> DecodedFix {
+    decode_position_block(&position_block)
+}
+
+/// Decode an extended block from `extract_gga_extended`, or `None` if the layout version is unknown.
+#[pyfunction(name = "decode_extended_block")]
+fn py_decode_extended_block(extended_block: [u8; 16]) -> Option<ExtendedFix> {
+    decode_extended_block(&extended_block)
+}
+
+/// Frame every complete sentence of a raw UART dump and extract the fix of each GGA sentence containing one.
+///
+/// Returns `(offset, fix)` pairs, where `offset` is the index of the `$` in the dump.
+#[pyfunction]
+fn replay_gga(dump: &[u8]) -> Vec<(usize, ExtendedFix)> {
+    if dump.is_empty() {
+        return Vec::new();
+    }
+    let mut extended_block = [0; 16];
+    Framer::with_length(dump, 0, dump.len())
+        .filter(|&(sentence_begin, _)| is_gga(dump, sentence_begin))
+        .filter_map(|(sentence_begin, _)| {
+            extract_gga_extended(dump, sentence_begin, &mut extended_block)
+                .then(|| decode_extended_block(&extended_block))
+                .flatten()
+                .map(|fix| (sentence_begin, fix))
+        })
+        .collect()
+}
+
+/// The `gga_extract` Python module.
+#[pymodule]
+fn gga_extract(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<DecodedFix>()?;
+    module.add_class::<ExtendedFix>()?;
+    module.add_function(wrap_pyfunction!(py_is_gga, module)?)?;
+    module.add_function(wrap_pyfunction!(py_extract_gga, module)?)?;
+    module.add_function(wrap_pyfunction!(py_extract_gga_extended, module)?)?;
+    module.add_function(wrap_pyfunction!(py_extract_gga_e7, module)?)?;
+    module.add_function(wrap_pyfunction!(py_decode_position_block, module)?)?;
+    module.add_function(wrap_pyfunction!(py_decode_extended_block, module)?)?;
+    module.add_function(wrap_pyfunction!(replay_gga, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::GGA_HIGH_LATITUDE;
+
+    #[test]
+    fn test_replay_gga() {
+        let fixes = replay_gga(DUMP);
+        assert_eq!(fixes.len(), 2);
+        assert_eq!(fixes[0].0, 18);
+        assert_eq!(fixes[1].0, 220);
+        assert_eq!(fixes[0].1, fixes[1].1);
+        assert_eq!(fixes[0].1.position.lat_minutes, 165421973);
+        assert_eq!(fixes[0].1.time, 18720);
+        assert!(replay_gga(b"").is_empty());
+
+        // Above the latitude the 10-byte block wraps at
+        let fixes = replay_gga(GGA_HIGH_LATITUDE);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].1.position.lat_e7(), 515020575);
+        assert_eq!(fixes[0].1.position.lon_e7(), -1275720);
+    }
+
+    #[test]
+    fn test_extract_gga_e7() {
+        let position_block = py_extract_gga_e7(GGA_HIGH_LATITUDE, 0).unwrap();
+        assert_eq!(position_block[..4], 515020575i32.to_be_bytes());
+        assert_eq!(position_block[4..8], (-1275720i32).to_be_bytes());
+        assert_eq!(py_extract_gga_e7(&DUMP[167..], 0), None);
+        assert_eq!(py_extract_gga_e7(b"", 0), None);
+    }
+
+    #[test]
+    fn test_extract_and_decode() {
+        assert!(py_is_gga(&DUMP[18..], 0));
+        let position_block = py_extract_gga(&DUMP[18..], 0).unwrap();
+        assert_eq!(py_decode_position_block(position_block).hdop, 28);
+        let extended_block = py_extract_gga_extended(&DUMP[18..], 0).unwrap();
+        assert_eq!(py_decode_extended_block(extended_block).unwrap().altitude, 1034);
+        assert_eq!(py_extract_gga(&DUMP[167..], 0), None);
+        assert!(!py_is_gga(b"", 0));
+    }
+
+    const DUMP: &[u8] = b"73,M,41.1,M,,*59\r\n\
+        $GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGGA,0512$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNGGA,0512";
+}
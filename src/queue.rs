@@ -0,0 +1,120 @@
+//! Handoff of extracted fixes from the UART or DMA interrupt to the main loop through a `heapless::spsc::Queue`.
+use crate::buffer::NmeaBuffer;
+use crate::fix::{GgaFix, extract_gga_struct};
+use crate::trace::log_debug;
+use heapless::spsc::{Consumer, Producer, Queue};
+
+/// Interrupt side of a fix queue, see [`split_fix_queue`].
+pub struct FixProducer<'a> {
+    producer: Producer<'a, GgaFix>,
+    dropped: u32,
+}
+
+impl FixProducer<'_> {
+    /// Extract the fix from a GGA sentence in a circular buffer and enqueue it.
+    ///
+    /// ### Arguments
+    /// * `buffer` - A circular buffer containing NMEA 0183 data.
+    /// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+    ///
+    /// ### Returns
+    /// If the sentence contains a fix and it was enqueued. A fix that does not fit because the main loop has fallen
+    /// behind is dropped and counted in [`FixProducer::dropped`].
+    #[inline]
+    pub fn push_gga<B: NmeaBuffer + ?Sized>(&mut self, buffer: &B, sentence_begin: usize) -> bool {
+        let Some(fix) = extract_gga_struct(buffer, sentence_begin) else {
+            return false;
+        };
+        if self.producer.enqueue(fix).is_err() {
+            self.dropped = self.dropped.wrapping_add(1);
+            log_debug!("fix queue full, dropped fix from GGA at {}", sentence_begin);
+            return false;
+        }
+        true
+    }
+
+    /// Number of fixes dropped because the queue was full, wrapping.
+    #[inline]
+    pub const fn dropped(&self) -> u32 {
+        self.dropped
+    }
+}
+
+/// Main loop side of a fix queue, see [`split_fix_queue`].
+pub struct FixConsumer<'a> {
+    consumer: Consumer<'a, GgaFix>,
+}
+
+impl FixConsumer<'_> {
+    /// Take the oldest fix not yet consumed.
+    #[inline]
+    pub fn pop(&mut self) -> Option<GgaFix> {
+        self.consumer.dequeue()
+    }
+
+    /// Number of fixes waiting.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.consumer.len()
+    }
+
+    /// If no fixes are waiting.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.consumer.len() == 0
+    }
+}
+
+/// Split a queue into a producer for the interrupt handler and a consumer for the main loop.
+///
+/// The queue is usually a `static`, so the halves can be moved into the interrupt and main contexts.
+#[inline]
+pub fn split_fix_queue<const N: usize>(queue: &mut Queue<GgaFix, N>) -> (FixProducer<'_>, FixConsumer<'_>) {
+    let (producer, consumer) = queue.split();
+    (FixProducer { producer, dropped: 0 }, FixConsumer { consumer })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_NO_FIX, GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_fix_queue() {
+        let mut queue: Queue<GgaFix, 4> = Queue::new();
+        let (mut producer, mut consumer) = split_fix_queue(&mut queue);
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert!(!producer.push_gga(&buffer, i));
+
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            let expected_fix = extract_gga_struct(&buffer, i).unwrap();
+            assert!(producer.push_gga(&buffer, i));
+            assert!(producer.push_gga(&buffer, i));
+            assert_eq!(consumer.len(), 2);
+            assert_eq!(consumer.pop(), Some(expected_fix));
+            assert_eq!(consumer.pop(), Some(expected_fix));
+            assert!(consumer.is_empty());
+        }
+        assert_eq!(producer.dropped(), 0);
+    }
+
+    #[test]
+    fn test_fix_queue_full() {
+        let mut queue: Queue<GgaFix, 4> = Queue::new();
+        let (mut producer, mut consumer) = split_fix_queue(&mut queue);
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_WITH_FIX, 1000);
+        // Capacity is one less than N
+        for _ in 0..3 {
+            assert!(producer.push_gga(&buffer, 1000));
+        }
+        assert!(!producer.push_gga(&buffer, 1000));
+        assert!(!producer.push_gga(&buffer, 1000));
+        assert_eq!(producer.dropped(), 2);
+        assert_eq!(consumer.len(), 3);
+        assert!(consumer.pop().is_some());
+        assert!(producer.push_gga(&buffer, 1000));
+    }
+}
@@ -0,0 +1,147 @@
+//! Detect and parse RMB (Recommended Minimum Navigation Information) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a RMB sentence.
+#[inline]
+pub fn is_rmb<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'R'
+        && byte_at(buffer, sentence_begin + 4) == b'M'
+        && byte_at(buffer, sentence_begin + 5) == b'B'
+}
+
+/// Extract navigation data to the destination waypoint from a RMB sentence in a circular buffer.
+///
+/// The waypoint block layout is:
+/// * `[0..9]` - Destination latitude, longitude and hemisphere flags, same encoding as
+///   [`extract_gga`](crate::extract_gga).
+/// * `[9..11]` - Cross-track error in hundredths of a nautical mile, big-endian.
+/// * `[11]` - Flags: bit 2 is set if the sentence is valid, bit 1 if the arrival circle has been entered and bit 0 if
+///   the direction to steer is right.
+/// * `[12..14]` - Range to destination in tenths of a nautical mile, big-endian.
+/// * `[14..16]` - True bearing to destination in tenths of a degree, big-endian.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the RMB sentence in the buffer.
+/// * `waypoint_block` - Output buffer where parsed data will be stored (16 bytes).
+///
+/// ### Returns
+/// If the sentence contains a destination, the status is valid and, from NMEA 2.3, the mode indicator is not `N`.
+#[inline]
+pub fn extract_rmb<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, waypoint_block: &mut [u8; 16]) -> bool {
+    let (cross_track, end) = parse_decimal(buffer, sentence_begin + 9, 2);
+    let direction = skip_field(buffer, end);
+
+    // Skip steer direction, origin and destination waypoint IDs, which are variable width
+    let index = skip_field(buffer, skip_field(buffer, skip_field(buffer, direction)));
+
+    // Check destination latitude field
+    if byte_at(buffer, index) == b',' {
+        return false;
+    }
+
+    // Waypoint latitude and longitude precision varies between receivers
+    let (lat, end) = parse_decimal(buffer, index, 6);
+    let lat_hemisphere = skip_field(buffer, end);
+    let (lon, end) = parse_decimal(buffer, skip_field(buffer, lat_hemisphere), 5);
+    let lon_hemisphere = skip_field(buffer, end);
+
+    waypoint_block[0] = (lat >> 24) as u8;
+    waypoint_block[1] = (lat >> 16) as u8;
+    waypoint_block[2] = (lat >> 8) as u8;
+    waypoint_block[3] = lat as u8;
+    waypoint_block[4] = (lon >> 24) as u8;
+    waypoint_block[5] = (lon >> 16) as u8;
+    waypoint_block[6] = (lon >> 8) as u8;
+    waypoint_block[7] = lon as u8;
+    waypoint_block[8] =
+        ((byte_at(buffer, lat_hemisphere) == b'N') as u8) << 1 | (byte_at(buffer, lon_hemisphere) == b'E') as u8;
+
+    let cross_track = if cross_track < 65536 { cross_track as u16 } else { 65535 };
+    waypoint_block[9] = (cross_track >> 8) as u8;
+    waypoint_block[10] = cross_track as u8;
+
+    let (range, end) = parse_decimal(buffer, skip_field(buffer, lon_hemisphere), 1);
+    let range = if range < 65536 { range as u16 } else { 65535 };
+    waypoint_block[12] = (range >> 8) as u8;
+    waypoint_block[13] = range as u8;
+
+    let (bearing, end) = parse_decimal(buffer, skip_field(buffer, end), 1);
+    waypoint_block[14] = (bearing >> 8) as u8;
+    waypoint_block[15] = bearing as u8;
+
+    // Skip closing velocity, the mode indicator is only present from NMEA 2.3
+    let arrival = skip_field(buffer, skip_field(buffer, end));
+    let mode = skip_field(buffer, arrival);
+    let valid = byte_at(buffer, sentence_begin + 7) == b'A' && byte_at(buffer, mode) != b'N';
+    waypoint_block[11] = ((valid as u8) << 2)
+        | ((byte_at(buffer, arrival) == b'A') as u8) << 1
+        | (byte_at(buffer, direction) == b'R') as u8;
+
+    valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_rmb() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, RMB[0].0, i);
+            assert!(!is_rmb(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_rmb(&buffer, i));
+            assert!(!is_rmb(&buffer, (i + 1) & 1023));
+            assert!(!crate::is_rmc(&buffer, i));
+        }
+    }
+
+    #[test]
+    fn test_rmb_no_destination() {
+        let mut waypoint_block = [0; 16];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, RMB_NO_DESTINATION, i);
+            let parsed = extract_rmb(&buffer, i, &mut waypoint_block);
+            assert!(!parsed);
+            assert_eq!(waypoint_block, [0; 16]);
+        }
+    }
+
+    #[test]
+    fn test_rmb() {
+        let mut waypoint_block = [0; 16];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_waypoint_block, expected_valid) in RMB.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_rmb(&buffer, i, &mut waypoint_block);
+                assert_eq!(parsed, *expected_valid);
+                assert_eq!(waypoint_block, *expected_waypoint_block);
+            }
+        }
+    }
+
+    const RMB_NO_DESTINATION: &[u8] = b"$GPRMB,V,,,,,,,,,,,,V,N*04\r\n";
+
+    const RMB: [(&[u8], [u8; 16], bool); 3] = [
+        (
+            b"$GPRMB,A,0.66,L,003,004,3917.24,N,12309.57,W,001.3,052.5,000.5,V*27\r\n",
+            [233, 124, 86, 192, 73, 94, 233, 200, 2, 0, 66, 4, 0, 13, 2, 13],
+            true,
+        ),
+        (
+            b"$GPRMB,A,4.08,R,START,DEST,2734.21973,S,15303.08927,E,123.4,359.9,12.3,A,D*3E\r\n",
+            [162, 248, 225, 210, 91, 54, 169, 63, 1, 1, 152, 7, 4, 210, 14, 15],
+            true,
+        ),
+        (
+            b"$GNRMB,A,0.00,L,,WP1,3944.5,N,00459.1665,E,0.0,0.0,,A,N*76\r\n",
+            [235, 28, 75, 32, 2, 188, 161, 234, 3, 0, 0, 2, 0, 0, 0, 0],
+            false,
+        ),
+    ];
+}
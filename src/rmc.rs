@@ -0,0 +1,147 @@
+//! Detect and parse RMC (Recommended Minimum Specific GNSS Data) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, parse_latitude, parse_longitude, skip_field};
+
+/// Determine if the sentence is a RMC sentence.
+#[inline]
+pub fn is_rmc<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'R'
+        && byte_at(buffer, sentence_begin + 4) == b'M'
+        && byte_at(buffer, sentence_begin + 5) == b'C'
+}
+
+/// Extract position, speed and course data from a RMC sentence in a circular buffer.
+///
+/// The motion block layout is:
+/// * `[0..4]` - Latitude, big-endian, same encoding as [`extract_gga`](crate::extract_gga).
+/// * `[4..8]` - Longitude, big-endian, same encoding as [`extract_gga`](crate::extract_gga).
+/// * `[8]` - Flags: bit 2 is set if the status is valid (`A`), bit 1 if north, bit 0 if east.
+/// * `[9..11]` - Speed over ground in hundredths of a knot, big-endian.
+/// * `[11..13]` - Course over ground in hundredths of a degree, big-endian. 0 if the field is empty.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the RMC sentence in the buffer.
+/// * `motion_block` - Output buffer where parsed data will be stored (13 bytes).
+///
+/// ### Returns
+/// If the sentence contains a position and the status is valid.
+#[inline]
+pub fn extract_rmc<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, motion_block: &mut [u8; 13]) -> bool {
+    // Check latitude field
+    if byte_at(buffer, sentence_begin + 20) == b',' {
+        // No latitude field, no fix
+        return false;
+    }
+
+    let valid = byte_at(buffer, sentence_begin + 18) == b'A';
+
+    // Parse latitude
+    let lat = parse_latitude(buffer, sentence_begin + 20);
+
+    motion_block[0] = (lat >> 24) as u8;
+    motion_block[1] = (lat >> 16) as u8;
+    motion_block[2] = (lat >> 8) as u8;
+    motion_block[3] = lat as u8;
+
+    // Validity and latitude hemisphere
+    motion_block[8] = ((valid as u8) << 2) | ((byte_at(buffer, sentence_begin + 31) == b'N') as u8) << 1;
+
+    // Parse longitude
+    let lon = parse_longitude(buffer, sentence_begin + 33);
+
+    motion_block[4] = (lon >> 24) as u8;
+    motion_block[5] = (lon >> 16) as u8;
+    motion_block[6] = (lon >> 8) as u8;
+    motion_block[7] = lon as u8;
+
+    // Longitude hemisphere
+    motion_block[8] |= (byte_at(buffer, sentence_begin + 45) == b'E') as u8;
+
+    // Speed over ground is variable width
+    let (sog, end) = parse_decimal(buffer, sentence_begin + 47, 2);
+    let sog = if sog < 65536 { sog as u16 } else { 65535 };
+    motion_block[9] = (sog >> 8) as u8;
+    motion_block[10] = sog as u8;
+
+    // Course over ground is variable width
+    let (cog, _) = parse_decimal(buffer, skip_field(buffer, end), 2);
+    motion_block[11] = (cog >> 8) as u8;
+    motion_block[12] = cog as u8;
+
+    valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_rmc() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, RMC_VALID[0].0, i);
+            assert!(!is_rmc(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_rmc(&buffer, i));
+            assert!(!is_rmc(&buffer, (i + 1) & 1023));
+            assert!(!crate::is_gga(&buffer, i));
+        }
+    }
+
+    #[test]
+    fn test_rmc_no_position() {
+        let mut motion_block = [0; 13];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, RMC_NO_POSITION, i);
+            let parsed = extract_rmc(&buffer, i, &mut motion_block);
+            assert!(!parsed);
+            assert_eq!(motion_block, [0; 13]);
+        }
+    }
+
+    #[test]
+    fn test_rmc_invalid_with_position() {
+        let mut motion_block = [0; 13];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, RMC_INVALID_WITH_POSITION.0, i);
+            let parsed = extract_rmc(&buffer, i, &mut motion_block);
+            assert!(!parsed);
+            assert_eq!(motion_block, RMC_INVALID_WITH_POSITION.1);
+        }
+    }
+
+    #[test]
+    fn test_rmc_valid() {
+        let mut motion_block = [0; 13];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_motion_block) in RMC_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_rmc(&buffer, i, &mut motion_block);
+                assert!(parsed);
+                assert_eq!(motion_block, *expected_motion_block);
+            }
+        }
+    }
+
+    const RMC_NO_POSITION: &[u8] = b"$GNRMC,051154.000,V,,,,,,,150625,,,N*52\r\n";
+
+    const RMC_INVALID_WITH_POSITION: (&[u8], [u8; 13]) = (
+        b"$GNRMC,181501.000,V,3615.12012,S,06357.25158,W,0.00,,311224,,,N*7F\r\n",
+        [215, 122, 90, 248, 37, 228, 101, 102, 0, 0, 0, 0, 0],
+    );
+
+    const RMC_VALID: [(&[u8], [u8; 13]); 2] = [
+        (
+            b"$GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n",
+            [162, 248, 225, 210, 91, 54, 169, 63, 5, 0, 52, 48, 57],
+        ),
+        (
+            b"$GPRMC,181501.000,A,3944.50086,N,10459.16654,W,12.7,5.3,010125,,,D*48\r\n",
+            [235, 28, 78, 124, 62, 87, 107, 238, 6, 4, 246, 2, 18],
+        ),
+    ];
+}
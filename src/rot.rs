@@ -0,0 +1,93 @@
+//! Detect and parse ROT (Rate of Turn) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_signed_decimal, skip_field};
+
+/// Determine if the sentence is a ROT sentence.
+#[inline]
+pub fn is_rot<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'R'
+        && byte_at(buffer, sentence_begin + 4) == b'O'
+        && byte_at(buffer, sentence_begin + 5) == b'T'
+}
+
+/// Extract the rate of turn from a ROT sentence in a circular buffer.
+///
+/// The rate block layout is:
+/// * `[0..2]` - Rate of turn in tenths of a degree per minute, signed big-endian. Negative when turning to port.
+/// * `[2]` - 1 if the status is valid (`A`), otherwise 0.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the ROT sentence in the buffer.
+/// * `rate_block` - Output buffer where parsed data will be stored (3 bytes).
+///
+/// ### Returns
+/// If the sentence contains a rate of turn and the status is valid.
+#[inline]
+pub fn extract_rot<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, rate_block: &mut [u8; 3]) -> bool {
+    // Check rate field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        return false;
+    }
+
+    let (rate, end) = parse_signed_decimal(buffer, sentence_begin + 7, 1);
+    let rate = rate.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    rate_block[0] = (rate >> 8) as u8;
+    rate_block[1] = rate as u8;
+
+    let valid = byte_at(buffer, skip_field(buffer, end)) == b'A';
+    rate_block[2] = valid as u8;
+
+    valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_rot() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, ROT[0].0, i);
+            assert!(!is_rot(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_rot(&buffer, i));
+            assert!(!is_rot(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_rot_empty() {
+        let mut rate_block = [0; 3];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, ROT_EMPTY, i);
+            let parsed = extract_rot(&buffer, i, &mut rate_block);
+            assert!(!parsed);
+            assert_eq!(rate_block, [0; 3]);
+        }
+    }
+
+    #[test]
+    fn test_rot() {
+        let mut rate_block = [0; 3];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_rate_block, expected_valid) in ROT.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_rot(&buffer, i, &mut rate_block);
+                assert_eq!(parsed, *expected_valid);
+                assert_eq!(rate_block, *expected_rate_block);
+            }
+        }
+    }
+
+    const ROT_EMPTY: &[u8] = b"$HEROT,,V*12\r\n";
+
+    const ROT: [(&[u8], [u8; 3], bool); 3] = [
+        (b"$HEROT,-12.5,A*30\r\n", [255, 131, 1], true),
+        (b"$TIROT,3.20,A*0A\r\n", [0, 32, 1], true),
+        (b"$HEROT,720.0,V*39\r\n", [28, 32, 0], false),
+    ];
+}
@@ -0,0 +1,319 @@
+//! Sentence bookkeeping across DMA idle line interrupts.
+use crate::buffer::NmeaBuffer;
+use crate::frame::{Framer, ndtr_write_index};
+use crate::parse::MAX_SENTENCE_LENGTH;
+use crate::sentence::{Sentence, classify};
+use crate::trace::log_debug;
+
+/// Interrupt that prompted a scan of a circular DMA buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DmaEvent {
+    /// The UART line went idle after receiving.
+    Idle,
+    /// The DMA filled the first half of the buffer.
+    HalfTransfer,
+    /// The DMA filled the second half of the buffer and wrapped.
+    TransferComplete,
+}
+
+impl DmaEvent {
+    /// NDTR value at the buffer boundary that raised the event, if any.
+    ///
+    /// Saturates at `u16::MAX` for buffers longer than a DMA transfer can be.
+    #[inline]
+    pub const fn boundary_ndtr(self, buffer_len: usize) -> Option<u16> {
+        let boundary = match self {
+            DmaEvent::Idle => return None,
+            DmaEvent::HalfTransfer => buffer_len / 2,
+            DmaEvent::TransferComplete => buffer_len,
+        };
+        Some(if boundary > u16::MAX as usize {
+            u16::MAX
+        } else {
+            boundary as u16
+        })
+    }
+}
+
+/// Tracks where the next sentence begins and the last NDTR value between idle line interrupts.
+///
+/// Replaces the `sentence_begin` and [`calculate_sentence_length`](crate::calculate_sentence_length) bookkeeping
+/// otherwise done in the interrupt handler. A sentence still being received is carried over to the next interrupt,
+/// and one that grows past the NMEA 0183 length limit without ending is dropped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SentenceScanner {
+    sentence_begin: usize,
+    ndtr: u16,
+}
+
+impl SentenceScanner {
+    /// Create a scanner for a DMA transfer that has just been started at the beginning of the buffer.
+    #[inline]
+    pub const fn new() -> SentenceScanner {
+        SentenceScanner {
+            sentence_begin: 0,
+            ndtr: 0,
+        }
+    }
+
+    /// Index where the next scan will begin.
+    #[inline]
+    pub const fn sentence_begin(&self) -> usize {
+        self.sentence_begin
+    }
+
+    /// NDTR value passed to the last call of [`SentenceScanner::on_idle`].
+    #[inline]
+    pub const fn ndtr(&self) -> u16 {
+        self.ndtr
+    }
+
+    /// Scan the sentences received since the last interrupt.
+    ///
+    /// Sentences not consumed from the returned iterator are reported again by the next call.
+    ///
+    /// ### Arguments
+    /// * `buffer` - The circular buffer the DMA is writing to.
+    /// * `ndtr` - Current value of the DMA NDTR register.
+    #[inline]
+    pub fn on_idle<'a, B: NmeaBuffer + ?Sized>(&'a mut self, buffer: &'a B, ndtr: u16) -> Sentences<'a, B> {
+        self.ndtr = ndtr;
+        Sentences {
+            framer: Framer::new(buffer, self.sentence_begin, ndtr),
+            scanner: self,
+        }
+    }
+
+    /// Scan the sentences received since the last DMA event.
+    ///
+    /// Call from the idle line, half transfer and transfer complete interrupts so no more than half the buffer is
+    /// received between scans. The newly received region ends at the live NDTR position, or at the event's buffer
+    /// boundary if the register has not caught up with it yet, as when it is read mid-reload.
+    ///
+    /// ### Arguments
+    /// * `buffer` - The circular buffer the DMA is writing to.
+    /// * `event` - The interrupt being serviced.
+    /// * `ndtr` - Current value of the DMA NDTR register.
+    #[inline]
+    pub fn on_dma_event<'a, B: NmeaBuffer + ?Sized>(
+        &'a mut self,
+        buffer: &'a B,
+        event: DmaEvent,
+        ndtr: u16,
+    ) -> Sentences<'a, B> {
+        let len = buffer.len();
+        let previous_ndtr = self.ndtr;
+        let received =
+            |ndtr: u16| Some((ndtr_write_index(len, ndtr)? + len - ndtr_write_index(len, previous_ndtr)?) % len);
+        let ndtr = match event.boundary_ndtr(len) {
+            // A boundary more than half a buffer ahead was already scanned past
+            Some(boundary_ndtr)
+                if received(boundary_ndtr)
+                    .is_some_and(|boundary| boundary <= len / 2 && Some(boundary) > received(ndtr)) =>
+            {
+                boundary_ndtr
+            }
+            _ => ndtr,
+        };
+        self.on_idle(buffer, ndtr)
+    }
+}
+
+/// Iterator over the sentences received since the last interrupt, returned by [`SentenceScanner::on_idle`].
+#[derive(Debug)]
+pub struct Sentences<'a, B: NmeaBuffer + ?Sized> {
+    framer: Framer<'a, B>,
+    scanner: &'a mut SentenceScanner,
+}
+
+impl<B: NmeaBuffer + ?Sized> Iterator for Sentences<'_, B> {
+    type Item = Sentence;
+
+    #[inline]
+    fn next(&mut self) -> Option<Sentence> {
+        let Some((begin, length)) = self.framer.next() else {
+            let buffer = self.framer.buffer;
+            let len = buffer.len();
+            let resume_index = self.framer.resume_index();
+            self.scanner.sentence_begin = match ndtr_write_index(len, self.scanner.ndtr) {
+                Some(write_index) if (write_index + len - resume_index) % len > MAX_SENTENCE_LENGTH => {
+                    // Not a sentence, skip it
+                    log_debug!("discarded unterminated sentence at {}", resume_index);
+                    write_index
+                }
+                _ => resume_index,
+            };
+            return None;
+        };
+        self.scanner.sentence_begin = self.framer.resume_index();
+        Some(Sentence {
+            begin,
+            length,
+            sentence_type: classify(self.framer.buffer, begin),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sentence::SentenceType;
+
+    fn receive(buffer: &mut [u8; 1024], ndtr: &mut u16, data: &[u8]) {
+        for &b in data {
+            buffer[1024 - *ndtr as usize] = b;
+            *ndtr = if *ndtr == 1 { 1024 } else { *ndtr - 1 };
+        }
+    }
+
+    #[test]
+    fn test_sentence_scanner() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut ndtr: u16 = 1024;
+        let mut scanner = SentenceScanner::new();
+        let mut sentences = 0;
+        let mut ggas = 0;
+        // Wraps the buffer several times, splitting sentences across interrupts and the wrap point
+        for _ in 0..40 {
+            for chunk in STREAM.chunks(37) {
+                receive(&mut buffer, &mut ndtr, chunk);
+                for sentence in scanner.on_idle(&buffer, ndtr) {
+                    assert_eq!(buffer[sentence.begin], b'$');
+                    assert_eq!(buffer[(sentence.begin + sentence.length - 1) & 1023], b'\n');
+                    sentences += 1;
+                    ggas += (sentence.sentence_type == SentenceType::Gga) as usize;
+                }
+                assert_eq!(scanner.ndtr(), ndtr);
+            }
+        }
+        assert_eq!(sentences, 40 * 3);
+        assert_eq!(ggas, 40 * 2);
+    }
+
+    #[test]
+    fn test_on_dma_event() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut ndtr: u16 = 1024;
+        let mut scanner = SentenceScanner::new();
+        let mut sentences = 0;
+        let mut received = 0;
+        for _ in 0..40 {
+            for chunk in STREAM.chunks(53) {
+                for &b in chunk {
+                    buffer[1024 - ndtr as usize] = b;
+                    received += 1;
+                    ndtr = if ndtr == 1 { 1024 } else { ndtr - 1 };
+                    // The register has moved on by a few bytes when the interrupt is serviced
+                    let event = match received % 1024 {
+                        515 => Some(DmaEvent::HalfTransfer),
+                        3 => Some(DmaEvent::TransferComplete),
+                        _ => None,
+                    };
+                    if let Some(event) = event {
+                        sentences += scanner.on_dma_event(&buffer, event, ndtr).count();
+                    }
+                }
+                sentences += scanner.on_dma_event(&buffer, DmaEvent::Idle, ndtr).count();
+            }
+        }
+        assert_eq!(sentences, 40 * 3);
+    }
+
+    #[test]
+    fn test_on_dma_event_stale_ndtr() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut ndtr: u16 = 1024;
+        let mut scanner = SentenceScanner::new();
+        receive(&mut buffer, &mut ndtr, &[b'x'; 1024 - STREAM.len()]);
+        assert_eq!(scanner.on_dma_event(&buffer, DmaEvent::Idle, ndtr).count(), 0);
+        let stale_ndtr = ndtr;
+        receive(&mut buffer, &mut ndtr, STREAM);
+        // The register has not been reloaded yet
+        assert_eq!(
+            scanner.on_dma_event(&buffer, DmaEvent::TransferComplete, stale_ndtr).count(),
+            3
+        );
+        assert_eq!(scanner.sentence_begin(), 0);
+    }
+
+    #[test]
+    fn test_on_dma_event_boundary_passed() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut ndtr: u16 = 1024;
+        let mut scanner = SentenceScanner::new();
+        receive(&mut buffer, &mut ndtr, &[b'x'; 514]);
+        receive(&mut buffer, &mut ndtr, &STREAM[..76]);
+        assert_eq!(scanner.on_dma_event(&buffer, DmaEvent::Idle, ndtr).count(), 1);
+        receive(&mut buffer, &mut ndtr, &STREAM[76..]);
+        // Serviced after an idle scan had already passed the half way point
+        assert_eq!(scanner.on_dma_event(&buffer, DmaEvent::HalfTransfer, ndtr).count(), 2);
+        assert_eq!(scanner.sentence_begin(), 514 + STREAM.len());
+        assert_eq!(DmaEvent::HalfTransfer.boundary_ndtr(1024), Some(512));
+        assert_eq!(DmaEvent::Idle.boundary_ndtr(1024), None);
+    }
+
+    #[test]
+    fn test_sentence_scanner_partial_consumption() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut ndtr: u16 = 1024;
+        let mut scanner = SentenceScanner::new();
+        receive(&mut buffer, &mut ndtr, STREAM);
+        assert_eq!(scanner.on_idle(&buffer, ndtr).next().map(|s| s.begin), Some(0));
+        assert_eq!(scanner.sentence_begin(), 75);
+        assert_eq!(scanner.on_idle(&buffer, ndtr).count(), 2);
+        assert_eq!(scanner.sentence_begin(), STREAM.len());
+    }
+
+    #[test]
+    fn test_sentence_scanner_overlong() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut ndtr: u16 = 1024;
+        let mut scanner = SentenceScanner::new();
+        receive(&mut buffer, &mut ndtr, b"$GNGGA,");
+        assert_eq!(scanner.on_idle(&buffer, ndtr).count(), 0);
+        assert_eq!(scanner.sentence_begin(), 0);
+        receive(&mut buffer, &mut ndtr, &[b'0'; MAX_SENTENCE_LENGTH]);
+        assert_eq!(scanner.on_idle(&buffer, ndtr).count(), 0);
+        assert_eq!(scanner.sentence_begin(), 7 + MAX_SENTENCE_LENGTH);
+    }
+
+    #[test]
+    fn test_sentence_scanner_out_of_range() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut ndtr: u16 = 1024;
+        let mut scanner = SentenceScanner::new();
+        receive(&mut buffer, &mut ndtr, STREAM);
+        assert_eq!(scanner.on_idle(&buffer, 1025).count(), 0);
+        assert_eq!(scanner.sentence_begin(), 0);
+        assert_eq!(scanner.on_idle(&buffer, ndtr).count(), 3);
+        assert_eq!(scanner.sentence_begin(), STREAM.len());
+
+        let mut scanner = SentenceScanner::new();
+        assert_eq!(scanner.on_idle(&[][..], 0).count(), 0);
+        assert_eq!(scanner.sentence_begin(), 0);
+    }
+
+    #[test]
+    fn test_on_dma_event_out_of_range() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut ndtr: u16 = 1024;
+        let mut scanner = SentenceScanner::new();
+        receive(&mut buffer, &mut ndtr, STREAM);
+        assert_eq!(scanner.on_dma_event(&buffer, DmaEvent::Idle, 1025).count(), 0);
+        assert_eq!(scanner.sentence_begin(), 0);
+        // The event's boundary stands in for a spurious NDTR read
+        let mut scanner = SentenceScanner::new();
+        assert_eq!(scanner.on_dma_event(&buffer, DmaEvent::HalfTransfer, 1025).count(), 3);
+        assert_eq!(scanner.sentence_begin(), 512);
+
+        let mut scanner = SentenceScanner::new();
+        assert_eq!(scanner.on_dma_event(&[][..], DmaEvent::TransferComplete, 0).count(), 0);
+        assert_eq!(scanner.sentence_begin(), 0);
+        assert_eq!(DmaEvent::TransferComplete.boundary_ndtr(70_000), Some(u16::MAX));
+        assert_eq!(DmaEvent::HalfTransfer.boundary_ndtr(140_000), Some(u16::MAX));
+    }
+
+    const STREAM: &[u8] = b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n";
+}
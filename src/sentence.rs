@@ -0,0 +1,149 @@
+//! Sentence type identification.
+use crate::buffer::NmeaBuffer;
+
+/// Type of a NMEA 0183 sentence, from the three characters following the talker ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SentenceType {
+    Dbt,
+    Dpt,
+    Dtm,
+    Gbs,
+    Gga,
+    Gll,
+    Gns,
+    Grs,
+    Gsa,
+    Gst,
+    Gsv,
+    Hdt,
+    Mtw,
+    Mwv,
+    Rmb,
+    Rmc,
+    Rot,
+    Vhw,
+    Vtg,
+    Xte,
+    Zda,
+    Unknown,
+}
+
+impl SentenceType {
+    /// Identify a sentence type from its three character code, e.g. `GGA`.
+    #[inline]
+    pub const fn from_code(code: [u8; 3]) -> SentenceType {
+        match &code {
+            b"DBT" => SentenceType::Dbt,
+            b"DPT" => SentenceType::Dpt,
+            b"DTM" => SentenceType::Dtm,
+            b"GBS" => SentenceType::Gbs,
+            b"GGA" => SentenceType::Gga,
+            b"GLL" => SentenceType::Gll,
+            b"GNS" => SentenceType::Gns,
+            b"GRS" => SentenceType::Grs,
+            b"GSA" => SentenceType::Gsa,
+            b"GST" => SentenceType::Gst,
+            b"GSV" => SentenceType::Gsv,
+            b"HDT" => SentenceType::Hdt,
+            b"MTW" => SentenceType::Mtw,
+            b"MWV" => SentenceType::Mwv,
+            b"RMB" => SentenceType::Rmb,
+            b"RMC" => SentenceType::Rmc,
+            b"ROT" => SentenceType::Rot,
+            b"VHW" => SentenceType::Vhw,
+            b"VTG" => SentenceType::Vtg,
+            b"XTE" => SentenceType::Xte,
+            b"ZDA" => SentenceType::Zda,
+            _ => SentenceType::Unknown,
+        }
+    }
+}
+
+/// Determine if the sentence is of the given type, e.g. `b"RMC"`, with the same comparison as [`is_gga`](crate::is_gga).
+///
+/// The type is usually a literal, so the comparison inlines to three byte compares.
+#[inline(always)]
+pub fn is_sentence<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, sentence_type: &[u8; 3]) -> bool {
+    buffer.at(sentence_begin + 3) == sentence_type[0]
+        && buffer.at(sentence_begin + 4) == sentence_type[1]
+        && buffer.at(sentence_begin + 5) == sentence_type[2]
+}
+
+/// Classify a sentence by reading its three type characters once.
+///
+/// A single call replaces testing each `is_xxx` function in turn.
+#[inline]
+pub fn classify<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> SentenceType {
+    SentenceType::from_code([
+        buffer.at(sentence_begin + 3),
+        buffer.at(sentence_begin + 4),
+        buffer.at(sentence_begin + 5),
+    ])
+}
+
+/// Location and type of a complete sentence in a circular buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sentence {
+    /// Index of the `$` in the buffer.
+    pub begin: usize,
+    /// Length of the sentence including `$` and `\r\n`.
+    pub length: usize,
+    /// Type of the sentence.
+    pub sentence_type: SentenceType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(SentenceType::from_code(*b"GGA"), SentenceType::Gga);
+        assert_eq!(SentenceType::from_code(*b"RMC"), SentenceType::Rmc);
+        assert_eq!(SentenceType::from_code(*b"ZDA"), SentenceType::Zda);
+        assert_eq!(SentenceType::from_code(*b"GGB"), SentenceType::Unknown);
+        assert_eq!(SentenceType::from_code(*b"gga"), SentenceType::Unknown);
+    }
+
+    #[test]
+    fn test_classify() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_sentence_type) in SENTENCE_TYPES.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(classify(&buffer, i), *expected_sentence_type);
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_sentence() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, _) in SENTENCE_TYPES.iter() {
+            let code: [u8; 3] = sentence[3..6].try_into().unwrap();
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(!is_sentence(&buffer, (i.wrapping_sub(1)) & 1023, &code));
+                assert!(is_sentence(&buffer, i, &code));
+                assert!(!is_sentence(&buffer, (i + 1) & 1023, &code));
+                assert_eq!(is_sentence(&buffer, i, b"GGA"), code == *b"GGA");
+            }
+        }
+    }
+
+    const SENTENCE_TYPES: [(&[u8], SentenceType); 6] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            SentenceType::Gga,
+        ),
+        (
+            b"$GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n",
+            SentenceType::Rmc,
+        ),
+        (b"$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F\r\n", SentenceType::Dtm),
+        (b"$HEHDT,5.1,T*2B\r\n", SentenceType::Hdt),
+        (b"$WIMWV,045.5,R,12.3,N,A*17\r\n", SentenceType::Mwv),
+        (b"$PUBX,00,051154.000,,,,,,*05\r\n", SentenceType::Unknown),
+    ];
+}
@@ -0,0 +1,190 @@
+//! Fix handoff between a DMA interrupt and a task, synchronised with `critical-section`.
+use crate::buffer::NmeaBuffer;
+use crate::fix::{GgaFix, extract_gga_struct};
+use crate::scanner::SentenceScanner;
+use crate::sentence::SentenceType;
+use crate::trace::log_debug;
+use core::cell::{Cell, RefCell};
+use critical_section::Mutex;
+
+/// Fixes waiting to be drained, oldest first from `head`.
+struct Fixes<const N: usize> {
+    fixes: [GgaFix; N],
+    head: usize,
+    len: usize,
+    dropped: u32,
+}
+
+/// A [`SentenceScanner`] and a queue of the newest `N` fixes, shared between an interrupt and a task.
+///
+/// The interrupt calls [`SharedScanner::on_idle`], and the task calls [`SharedScanner::pop`]. Sentences are parsed
+/// outside the critical section, which is only held to copy the scanner state and to queue each fix. When the task
+/// falls behind, the oldest fix is dropped to make room for the newest.
+pub struct SharedScanner<const N: usize> {
+    scanner: Mutex<Cell<SentenceScanner>>,
+    fixes: Mutex<RefCell<Fixes<N>>>,
+}
+
+impl<const N: usize> SharedScanner<N> {
+    /// Create a scanner for a DMA transfer that has just been started, with an empty queue.
+    #[inline]
+    pub const fn new() -> SharedScanner<N> {
+        SharedScanner {
+            scanner: Mutex::new(Cell::new(SentenceScanner::new())),
+            fixes: Mutex::new(RefCell::new(Fixes {
+                fixes: [GgaFix {
+                    lat: 0,
+                    lat_total_minutes: 0,
+                    lon: 0,
+                    flags: 0,
+                    hdop: 0,
+                    quality: 0,
+                }; N],
+                head: 0,
+                len: 0,
+                dropped: 0,
+            })),
+        }
+    }
+
+    /// Scan the sentences received since the last interrupt and queue the fix of each GGA sentence.
+    ///
+    /// Must only be called from one context, usually the DMA or UART idle line interrupt.
+    ///
+    /// ### Arguments
+    /// * `buffer` - The circular buffer the DMA is writing to.
+    /// * `ndtr` - Current value of the DMA NDTR register.
+    ///
+    /// ### Returns
+    /// Number of fixes queued.
+    #[inline]
+    pub fn on_idle<B: NmeaBuffer + ?Sized>(&self, buffer: &B, ndtr: u16) -> usize {
+        let mut scanner = critical_section::with(|cs| self.scanner.borrow(cs).get());
+        let mut queued = 0;
+        for sentence in scanner.on_idle(buffer, ndtr) {
+            if sentence.sentence_type != SentenceType::Gga {
+                continue;
+            }
+            if let Some(fix) = extract_gga_struct(buffer, sentence.begin) {
+                self.push(fix);
+                queued += 1;
+            }
+        }
+        critical_section::with(|cs| self.scanner.borrow(cs).set(scanner));
+        queued
+    }
+
+    /// Queue a fix, dropping the oldest if the queue is full.
+    #[inline]
+    pub fn push(&self, fix: GgaFix) {
+        critical_section::with(|cs| {
+            let mut fixes = self.fixes.borrow_ref_mut(cs);
+            if N == 0 {
+                fixes.dropped = fixes.dropped.wrapping_add(1);
+                return;
+            }
+            let tail = (fixes.head + fixes.len) % N;
+            fixes.fixes[tail] = fix;
+            if fixes.len == N {
+                fixes.head = (fixes.head + 1) % N;
+                fixes.dropped = fixes.dropped.wrapping_add(1);
+                log_debug!("fix queue full, dropped oldest fix");
+            } else {
+                fixes.len += 1;
+            }
+        });
+    }
+
+    /// Take the oldest queued fix.
+    #[inline]
+    pub fn pop(&self) -> Option<GgaFix> {
+        critical_section::with(|cs| {
+            let mut fixes = self.fixes.borrow_ref_mut(cs);
+            if fixes.len == 0 {
+                return None;
+            }
+            let fix = fixes.fixes[fixes.head];
+            fixes.head = (fixes.head + 1) % N;
+            fixes.len -= 1;
+            Some(fix)
+        })
+    }
+
+    /// Number of queued fixes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        critical_section::with(|cs| self.fixes.borrow_ref(cs).len)
+    }
+
+    /// If no fixes are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of fixes dropped to make room for newer ones, wrapping.
+    #[inline]
+    pub fn dropped(&self) -> u32 {
+        critical_section::with(|cs| self.fixes.borrow_ref(cs).dropped)
+    }
+}
+
+impl<const N: usize> Default for SharedScanner<N> {
+    #[inline]
+    fn default() -> SharedScanner<N> {
+        SharedScanner::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_shared_scanner() {
+        static SCANNER: SharedScanner<4> = SharedScanner::new();
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_WITH_FIX, 0);
+        let expected_fix = extract_gga_struct(&buffer, 0).unwrap();
+
+        let mut write_index = 0;
+        for _ in 0..100 {
+            shift_buffer(&mut buffer, WINDOW, write_index);
+            write_index = (write_index + WINDOW.len()) % 1024;
+            let ndtr = (1024 - write_index) as u16;
+            let queued = std::thread::scope(|scope| scope.spawn(|| SCANNER.on_idle(&buffer, ndtr)).join().unwrap());
+            assert_eq!(queued, 2);
+            assert_eq!(SCANNER.len(), 2);
+            assert_eq!(SCANNER.pop(), Some(expected_fix));
+            assert_eq!(SCANNER.pop(), Some(expected_fix));
+            assert_eq!(SCANNER.pop(), None);
+            assert!(SCANNER.is_empty());
+        }
+        assert_eq!(SCANNER.dropped(), 0);
+    }
+
+    #[test]
+    fn test_shared_scanner_drop_oldest() {
+        let scanner: SharedScanner<3> = SharedScanner::new();
+        for quality in 1..=5 {
+            scanner.push(GgaFix {
+                quality,
+                ..GgaFix::default()
+            });
+        }
+        assert_eq!(scanner.dropped(), 2);
+        assert_eq!(scanner.len(), 3);
+        for quality in 3..=5 {
+            assert_eq!(scanner.pop().unwrap().quality, quality);
+        }
+        assert_eq!(scanner.pop(), None);
+    }
+
+    const WINDOW: &[u8] = b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n";
+}
@@ -0,0 +1,124 @@
+//! Vector digit parsing and `$` scanning with `core::arch`.
+//!
+//! SSE2 and NEON are baseline on x86_64 and aarch64, so no runtime detection is needed. Other targets, including
+//! 32-bit Arm where the NEON intrinsics are not stable, use the scalar paths.
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+use core::arch::x86_64::*;
+
+/// Bytes compared per vector.
+#[cfg(any(target_arch = "aarch64", all(target_arch = "x86_64", target_feature = "sse2")))]
+const LANES: usize = 16;
+
+/// Convert eight ASCII digits packed first digit lowest into their 8-digit integer value.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[inline(always)]
+pub(crate) fn parse_8_digits(lanes: u64) -> u32 {
+    // SAFETY: SSE2 is enabled at compile time
+    unsafe {
+        let bytes = _mm_cvtsi64_si128(lanes as i64);
+        let digits = _mm_sub_epi16(
+            _mm_unpacklo_epi8(bytes, _mm_setzero_si128()),
+            _mm_set1_epi16(b'0' as i16),
+        );
+        // Multiply-add adjacent digits into 2-digit pairs, then adjacent pairs into 4-digit halves
+        let pairs = _mm_madd_epi16(digits, _mm_setr_epi16(10, 1, 10, 1, 10, 1, 10, 1));
+        let pairs = _mm_packs_epi32(pairs, pairs);
+        let halves = _mm_madd_epi16(pairs, _mm_setr_epi16(100, 1, 100, 1, 100, 1, 100, 1));
+        let halves = _mm_cvtsi128_si64(halves) as u64;
+        (halves as u32).wrapping_mul(10000).wrapping_add((halves >> 32) as u32)
+    }
+}
+
+/// Convert eight ASCII digits packed first digit lowest into their 8-digit integer value.
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+pub(crate) fn parse_8_digits(lanes: u64) -> u32 {
+    const PAIR_WEIGHTS: [u16; 8] = [10, 1, 10, 1, 10, 1, 10, 1];
+    const HALF_WEIGHTS: [u32; 4] = [100, 1, 100, 1];
+    // SAFETY: NEON is baseline on aarch64 and the weight loads read whole arrays
+    unsafe {
+        let digits = vsubq_u16(vmovl_u8(vcreate_u8(lanes)), vdupq_n_u16(b'0' as u16));
+        // Multiply adjacent digits and add them pairwise into 2-digit pairs, then 4-digit halves
+        let pairs = vpaddlq_u16(vmulq_u16(digits, vld1q_u16(PAIR_WEIGHTS.as_ptr())));
+        let halves = vpaddlq_u32(vmulq_u32(pairs, vld1q_u32(HALF_WEIGHTS.as_ptr())));
+        (vgetq_lane_u64::<0>(halves) as u32)
+            .wrapping_mul(10000)
+            .wrapping_add(vgetq_lane_u64::<1>(halves) as u32)
+    }
+}
+
+#[cfg(not(any(target_arch = "aarch64", all(target_arch = "x86_64", target_feature = "sse2"))))]
+pub(crate) use crate::parse::parse_8_digits;
+
+/// Index of the first `needle` in `haystack`.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[inline]
+pub(crate) fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    let mut index = 0;
+    // SAFETY: SSE2 is enabled at compile time and every load is within `haystack`
+    unsafe {
+        let needles = _mm_set1_epi8(needle as i8);
+        while index + LANES <= haystack.len() {
+            let chunk = _mm_loadu_si128(haystack.as_ptr().add(index) as *const __m128i);
+            let matches = _mm_movemask_epi8(_mm_cmpeq_epi8(chunk, needles));
+            if matches != 0 {
+                return Some(index + matches.trailing_zeros() as usize);
+            }
+            index += LANES;
+        }
+    }
+    haystack[index..].iter().position(|&b| b == needle).map(|position| index + position)
+}
+
+/// Index of the first `needle` in `haystack`.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+pub(crate) fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    let mut index = 0;
+    // SAFETY: NEON is baseline on aarch64 and every load is within `haystack`
+    unsafe {
+        let needles = vdupq_n_u8(needle);
+        while index + LANES <= haystack.len() {
+            let chunk = vld1q_u8(haystack.as_ptr().add(index));
+            if vmaxvq_u8(vceqq_u8(chunk, needles)) != 0 {
+                break;
+            }
+            index += LANES;
+        }
+    }
+    haystack[index..].iter().position(|&b| b == needle).map(|position| index + position)
+}
+
+/// Index of the first `needle` in `haystack`.
+#[cfg(not(any(target_arch = "aarch64", all(target_arch = "x86_64", target_feature = "sse2"))))]
+#[inline]
+pub(crate) fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    haystack.iter().position(|&b| b == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_8_digits() {
+        for digits in [*b"00000000", *b"12345678", *b"99999999", *b"27342197", *b"15303089"] {
+            let lanes = u64::from_le_bytes(digits);
+            assert_eq!(parse_8_digits(lanes), crate::parse::parse_8_digits(lanes));
+        }
+    }
+
+    #[test]
+    fn test_find_byte() {
+        let mut haystack = [b'x'; 40];
+        assert_eq!(find_byte(&haystack, b'$'), None);
+        for i in 0..haystack.len() {
+            haystack[i] = b'$';
+            assert_eq!(find_byte(&haystack, b'$'), Some(i));
+            assert_eq!(find_byte(&haystack[..i], b'$'), None);
+            haystack[i] = b'x';
+        }
+    }
+}
@@ -0,0 +1,425 @@
+//! Deterministic NMEA 0183 stream generation, for exercising the scanner and application logic without a receiver.
+use crate::checksum::finalize_sentence;
+use crate::decode::DecodedFix;
+use crate::emit::{emit_gga, write_digits, write_rmc, write_tenths};
+use crate::extended::ExtendedFix;
+use crate::parse::MAX_SENTENCE_LENGTH;
+use crate::time::{PackedDate, PackedTime};
+
+/// Satellite IDs reported by GSA sentences, the first [`SimulatorConfig::satellites`] of them.
+const SATELLITE_IDS: [u8; 12] = [5, 7, 13, 14, 15, 17, 19, 24, 28, 30, 41, 45];
+
+/// Largest HDOP in tenths the simulator reports, and the HDOP reported without a fix.
+const MAX_HDOP: u8 = 99;
+
+/// Starting state and behaviour of a [`Simulator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SimulatorConfig {
+    /// Starting latitude in minutes scaled by 10^5, as in [`DecodedFix`].
+    pub lat_minutes: u32,
+    /// Starting longitude in minutes scaled by 10^5.
+    pub lon_minutes: u32,
+    /// If the latitude is in the northern hemisphere.
+    pub north: bool,
+    /// If the longitude is in the eastern hemisphere.
+    pub east: bool,
+    /// Latitude change per epoch in minutes scaled by 10^5. The position stops at the pole and the antimeridian.
+    pub lat_step: i32,
+    /// Longitude change per epoch in minutes scaled by 10^5.
+    pub lon_step: i32,
+    /// Speed over ground reported by RMC in hundredths of a knot. Not derived from the steps.
+    pub speed: u16,
+    /// Course over ground reported by RMC in hundredths of a degree. Not derived from the steps.
+    pub course: u16,
+    /// Altitude above mean sea level in decimetres.
+    pub altitude: i32,
+    /// Number of satellites used in the fix, up to 12.
+    pub satellites: u8,
+    /// Smallest HDOP in tenths.
+    pub hdop_min: u8,
+    /// Largest HDOP in tenths, at most 9.9.
+    pub hdop_max: u8,
+    /// The fix is lost for the last [`SimulatorConfig::fix_loss_epochs`] epochs of every this many. 0 never loses it.
+    pub fix_loss_period: u32,
+    /// Number of epochs without a fix per period.
+    pub fix_loss_epochs: u32,
+    /// If each epoch includes a GGA sentence.
+    pub gga: bool,
+    /// If each epoch includes a RMC sentence.
+    pub rmc: bool,
+    /// If each epoch includes a GSA sentence.
+    pub gsa: bool,
+    /// UTC date of every epoch.
+    pub date: PackedDate,
+    /// UTC time of the first epoch, truncated to whole seconds. Epochs are one second apart.
+    pub time: PackedTime,
+    /// Seed of the HDOP variation. The same configuration always produces the same stream.
+    pub seed: u32,
+}
+
+impl Default for SimulatorConfig {
+    /// Walking north-east from 27°34.21973'S 153°03.08927'E at 1 Hz, losing the fix for 3 s every minute.
+    #[inline]
+    fn default() -> SimulatorConfig {
+        SimulatorConfig {
+            lat_minutes: 165_421_973,
+            lon_minutes: 918_308_927,
+            north: false,
+            east: true,
+            lat_step: -50,
+            lon_step: 50,
+            speed: 150,
+            course: 4500,
+            altitude: 1034,
+            satellites: 7,
+            hdop_min: 8,
+            hdop_max: 28,
+            fix_loss_period: 60,
+            fix_loss_epochs: 3,
+            gga: true,
+            rmc: true,
+            gsa: true,
+            date: PackedDate::new(2025, 6, 15),
+            time: PackedTime::new(5, 12, 0, 0),
+            seed: 0x2734_2197,
+        }
+    }
+}
+
+/// Writes one epoch of sentences at a time into a circular buffer, as a receiver's DMA would.
+#[derive(Clone, Debug)]
+pub struct Simulator {
+    config: SimulatorConfig,
+    epoch: u32,
+    write_index: usize,
+    rng: u32,
+}
+
+impl Simulator {
+    /// Create a simulator writing from the start of the buffer.
+    #[inline]
+    pub const fn new(config: SimulatorConfig) -> Simulator {
+        Simulator {
+            config,
+            epoch: 0,
+            write_index: 0,
+            // Xorshift never leaves zero
+            rng: if config.seed == 0 { 1 } else { config.seed },
+        }
+    }
+
+    /// Number of epochs written so far.
+    #[inline]
+    pub const fn epoch(&self) -> u32 {
+        self.epoch
+    }
+
+    /// Index of the next byte to be written, the DMA write position.
+    #[inline]
+    pub const fn write_index(&self) -> usize {
+        self.write_index
+    }
+
+    /// Value a DMA NDTR register counting down from the buffer length would hold.
+    ///
+    /// Saturates at 0 if `buffer_len` is not the length written to, and at `u16::MAX` for buffers longer than the
+    /// register can count.
+    #[inline]
+    pub const fn ndtr(&self, buffer_len: usize) -> u16 {
+        let remaining = buffer_len.saturating_sub(self.write_index);
+        if remaining > u16::MAX as usize {
+            u16::MAX
+        } else {
+            remaining as u16
+        }
+    }
+
+    /// If the next epoch has a fix.
+    #[inline]
+    pub const fn has_fix(&self) -> bool {
+        let period = self.config.fix_loss_period;
+        period == 0 || self.epoch % period < period.saturating_sub(self.config.fix_loss_epochs)
+    }
+
+    /// Write the sentences of the next epoch into the circular buffer and advance.
+    ///
+    /// ### Arguments
+    /// * `buffer` - The circular buffer to write to, at least as long as one epoch.
+    ///
+    /// ### Returns
+    /// Number of bytes written. Nothing is written to an empty buffer, but the epoch still advances.
+    pub fn step(&mut self, buffer: &mut [u8]) -> usize {
+        let config = self.config;
+        let has_fix = self.has_fix();
+        let seconds = (config.time.0 / 1000 + self.epoch) % 86_400;
+        let time = PackedTime(seconds * 1000);
+        let position = self.position();
+        let hdop = if has_fix { self.next_hdop() } else { MAX_HDOP };
+
+        let mut written = 0;
+        let mut sentence = [0; MAX_SENTENCE_LENGTH];
+        if config.gga {
+            let length = if has_fix {
+                let fix = ExtendedFix {
+                    position: DecodedFix { hdop, ..position },
+                    time: seconds,
+                    altitude: config.altitude,
+                    satellites: config.satellites,
+                    quality: 1,
+                };
+                emit_gga(&fix, &mut sentence)
+            } else {
+                write_gga_no_fix(&mut sentence, seconds)
+            };
+            written += self.write(buffer, &sentence[..length]);
+        }
+        if config.rmc {
+            let length = if has_fix {
+                write_rmc(
+                    &mut sentence,
+                    ddmm(position.lat_minutes),
+                    ddmm(position.lon_minutes),
+                    0b100 | (position.north as u8) << 1 | position.east as u8,
+                    config.speed,
+                    config.course,
+                    config.date,
+                    time,
+                )
+            } else {
+                write_rmc_no_fix(&mut sentence, config.date, seconds)
+            };
+            written += self.write(buffer, &sentence[..length]);
+        }
+        if config.gsa {
+            let length = write_gsa(&mut sentence, has_fix, config.satellites, hdop);
+            written += self.write(buffer, &sentence[..length]);
+        }
+
+        self.epoch = self.epoch.wrapping_add(1);
+        written
+    }
+
+    /// Position of the current epoch.
+    fn position(&self) -> DecodedFix {
+        let config = &self.config;
+        let epoch = self.epoch as i64;
+        let lat = config.lat_minutes as i64 + config.lat_step as i64 * epoch;
+        let lon = config.lon_minutes as i64 + config.lon_step as i64 * epoch;
+        DecodedFix {
+            lat_minutes: lat.clamp(0, 90 * 6_000_000 - 1) as u32,
+            lon_minutes: lon.clamp(0, 180 * 6_000_000 - 1) as u32,
+            north: config.north,
+            east: config.east,
+            hdop: 0,
+        }
+    }
+
+    /// Next HDOP between the configured bounds.
+    fn next_hdop(&mut self) -> u8 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 17;
+        self.rng ^= self.rng << 5;
+        let max = self.config.hdop_max.min(MAX_HDOP);
+        let min = self.config.hdop_min.min(max);
+        min + (self.rng % (max - min + 1) as u32) as u8
+    }
+
+    /// Copy a sentence into the circular buffer at the write position.
+    fn write(&mut self, buffer: &mut [u8], sentence: &[u8]) -> usize {
+        if buffer.is_empty() {
+            return 0;
+        }
+        for &b in sentence {
+            buffer[self.write_index] = b;
+            self.write_index = (self.write_index + 1) % buffer.len();
+        }
+        sentence.len()
+    }
+}
+
+/// Minutes scaled by 10^5 as `ddmm.mmmmm` or `dddmm.mmmmm` scaled by 10^5.
+#[inline(always)]
+fn ddmm(minutes: u32) -> u32 {
+    minutes / 6_000_000 * 10_000_000 + minutes % 6_000_000
+}
+
+/// Write a GGA sentence without a fix, e.g. `$GNGGA,051200.000,,,,,0,00,99.9,,,,,,*hh\r\n`.
+fn write_gga_no_fix(sentence: &mut [u8; MAX_SENTENCE_LENGTH], seconds: u32) -> usize {
+    sentence[0..7].copy_from_slice(b"$GNGGA,");
+    write_hhmmss(&mut sentence[7..], seconds);
+    sentence[13..37].copy_from_slice(b".000,,,,,0,00,99.9,,,,,,");
+    finalize_sentence(sentence, 37)
+}
+
+/// Write a void RMC sentence, e.g. `$GNRMC,051200.000,V,,,,,,,150625,,,N*hh\r\n`.
+fn write_rmc_no_fix(sentence: &mut [u8; MAX_SENTENCE_LENGTH], date: PackedDate, seconds: u32) -> usize {
+    sentence[0..7].copy_from_slice(b"$GNRMC,");
+    write_hhmmss(&mut sentence[7..], seconds);
+    sentence[13..26].copy_from_slice(b".000,V,,,,,,,");
+    write_digits(&mut sentence[26..], date.day as u32 % 100, 2);
+    write_digits(&mut sentence[28..], date.month as u32 % 100, 2);
+    write_digits(&mut sentence[30..], date.year as u32 % 100, 2);
+    sentence[32..36].copy_from_slice(b",,,N");
+    finalize_sentence(sentence, 36)
+}
+
+/// Write a GSA sentence, e.g. `$GNGSA,A,3,05,07,13,14,15,17,19,,,,,,3.5,1.4,2.1*hh\r\n`.
+///
+/// VDOP is reported as 1.5 times HDOP and PDOP as their sum, which is enough for tests that only check the values
+/// flow through.
+fn write_gsa(sentence: &mut [u8; MAX_SENTENCE_LENGTH], has_fix: bool, satellites: u8, hdop: u8) -> usize {
+    sentence[0..9].copy_from_slice(b"$GNGSA,A,");
+    sentence[9] = if has_fix { b'3' } else { b'1' };
+    let mut index = 10;
+    let used = if has_fix { satellites as usize } else { 0 };
+    for (slot, &id) in SATELLITE_IDS.iter().enumerate() {
+        sentence[index] = b',';
+        index += 1;
+        if slot < used {
+            write_digits(&mut sentence[index..], id as u32, 2);
+            index += 2;
+        }
+    }
+    let vdop = hdop as u32 * 3 / 2;
+    for dop in [hdop as u32 + vdop, hdop as u32, vdop] {
+        sentence[index] = b',';
+        index = write_tenths(sentence, index + 1, dop as i32);
+    }
+    finalize_sentence(sentence, index)
+}
+
+/// Write a time of day in whole seconds as `hhmmss`.
+#[inline(always)]
+fn write_hhmmss(out: &mut [u8], seconds: u32) {
+    write_digits(out, seconds / 3600, 2);
+    write_digits(&mut out[2..], seconds / 60 % 60, 2);
+    write_digits(&mut out[4..], seconds % 60, 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::verify_checksum;
+    use crate::extended::{decode_extended_block, extract_gga_extended};
+    use crate::gsa::extract_gsa;
+    use crate::rmc::extract_rmc;
+    use crate::scanner::SentenceScanner;
+    use crate::sentence::SentenceType;
+
+    #[test]
+    fn test_simulator() {
+        let config = SimulatorConfig {
+            fix_loss_period: 10,
+            fix_loss_epochs: 2,
+            ..SimulatorConfig::default()
+        };
+        let mut simulator = Simulator::new(config);
+        let mut scanner = SentenceScanner::new();
+        let mut buffer = [0; 1024];
+        let mut extended_block = [0; 16];
+        let mut motion_block = [0; 13];
+        let mut dop_block = [0; 7];
+        let mut hdop = 0;
+        // Enough epochs to wrap the buffer several times
+        for epoch in 0..100 {
+            let has_fix = simulator.has_fix();
+            assert_eq!(has_fix, epoch % 10 < 8);
+            simulator.step(&mut buffer);
+
+            let mut types = [SentenceType::Unknown; 3];
+            for (i, sentence) in scanner.on_idle(&buffer, simulator.ndtr(buffer.len())).enumerate() {
+                assert!(verify_checksum(&buffer, sentence.begin, sentence.length));
+                types[i] = sentence.sentence_type;
+                match sentence.sentence_type {
+                    SentenceType::Gga => {
+                        assert_eq!(
+                            extract_gga_extended(&buffer, sentence.begin, &mut extended_block),
+                            has_fix
+                        );
+                        if has_fix {
+                            let fix = decode_extended_block(&extended_block).unwrap();
+                            assert_eq!(fix.position.lat_minutes, 165_421_973 - 50 * epoch);
+                            assert_eq!(fix.position.lon_minutes, 918_308_927 + 50 * epoch);
+                            assert!((8..=28).contains(&fix.position.hdop));
+                            hdop = fix.position.hdop as u16;
+                            assert_eq!(fix.time, 5 * 3600 + 12 * 60 + epoch);
+                            assert_eq!(fix.altitude, 1034);
+                        }
+                    }
+                    SentenceType::Rmc => {
+                        assert_eq!(extract_rmc(&buffer, sentence.begin, &mut motion_block), has_fix);
+                        if has_fix {
+                            assert_eq!(motion_block[9..13], [0, 150, 17, 148]);
+                        }
+                    }
+                    SentenceType::Gsa => {
+                        assert_eq!(extract_gsa(&buffer, sentence.begin, &mut dop_block), has_fix);
+                        if has_fix {
+                            assert_eq!(dop_block[0], 3);
+                            assert_eq!(dop_block[3..5], (hdop * 10).to_be_bytes());
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            assert_eq!(types, [SentenceType::Gga, SentenceType::Rmc, SentenceType::Gsa]);
+        }
+    }
+
+    #[test]
+    fn test_simulator_deterministic() {
+        let mut buffers = [[0; 1024]; 2];
+        for buffer in buffers.iter_mut() {
+            let mut simulator = Simulator::new(SimulatorConfig::default());
+            for _ in 0..20 {
+                simulator.step(buffer);
+            }
+        }
+        assert_eq!(buffers[0], buffers[1]);
+
+        let mut buffer = [0; 1024];
+        let mut simulator = Simulator::new(SimulatorConfig {
+            rmc: false,
+            gsa: false,
+            ..SimulatorConfig::default()
+        });
+        let length = simulator.step(&mut buffer);
+        assert_eq!(
+            core::str::from_utf8(&buffer[..length]).unwrap(),
+            "$GNGGA,051200.000,2734.21973,S,15303.08927,E,1,07,2.2,103.4,M,,,,*07\r\n"
+        );
+        assert_eq!(simulator.step(&mut []), 0);
+        assert_eq!(simulator.epoch(), 2);
+    }
+
+    #[test]
+    fn test_simulator_ndtr_out_of_range() {
+        let mut buffer = [0; 1024];
+        let mut simulator = Simulator::new(SimulatorConfig::default());
+        simulator.step(&mut buffer);
+        assert!(simulator.write_index() > 0);
+        assert_eq!(simulator.ndtr(1024) as usize, 1024 - simulator.write_index());
+        assert_eq!(simulator.ndtr(0), 0);
+        assert_eq!(simulator.ndtr(100_000), u16::MAX);
+    }
+
+    #[test]
+    fn test_simulator_gsa_two_digit_dop() {
+        let mut buffer = [0; 1024];
+        let mut dop_block = [0; 7];
+        let mut simulator = Simulator::new(SimulatorConfig {
+            gga: false,
+            rmc: false,
+            hdop_min: 80,
+            hdop_max: 80,
+            ..SimulatorConfig::default()
+        });
+        let length = simulator.step(&mut buffer);
+        assert_eq!(
+            core::str::from_utf8(&buffer[..length - 5]).unwrap(),
+            "$GNGSA,A,3,05,07,13,14,15,17,19,,,,,,20.0,8.0,12.0"
+        );
+        assert!(extract_gsa(&buffer, 0, &mut dop_block));
+        assert_eq!(dop_block, [3, 7, 208, 3, 32, 4, 176]);
+    }
+}
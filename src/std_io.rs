@@ -0,0 +1,143 @@
+//! GGA fixes from `std::io::Read` sources such as raw NMEA log files.
+use crate::fix::GgaFix;
+use crate::stream::GgaStream;
+use std::io::{ErrorKind, Read, Result};
+
+/// Number of bytes requested from the reader at a time.
+const CHUNK_LENGTH: usize = 4096;
+
+/// Reads a `std::io::Read` source and yields the fixes of its GGA sentences.
+///
+/// Bytes are fed through [`GgaStream`], so the log may be split anywhere, include other sentence types and binary
+/// data, or start part way through a sentence. Reads are made in 4 KiB chunks, so files need no extra buffering.
+#[derive(Debug)]
+pub struct LogReader<R> {
+    reader: R,
+    stream: GgaStream,
+    chunk: [u8; CHUNK_LENGTH],
+    index: usize,
+    length: usize,
+}
+
+impl<R: Read> LogReader<R> {
+    /// Create a reader waiting for the start of a sentence.
+    #[inline]
+    pub fn new(reader: R) -> LogReader<R> {
+        LogReader {
+            reader,
+            stream: GgaStream::new(),
+            chunk: [0; CHUNK_LENGTH],
+            index: 0,
+            length: 0,
+        }
+    }
+
+    /// Read until a GGA sentence containing a fix is complete.
+    ///
+    /// ### Returns
+    /// The fix, `None` at the end of the input, or the reader's error. Interrupted reads are retried.
+    #[inline]
+    pub fn next_fix(&mut self) -> Result<Option<GgaFix>> {
+        loop {
+            while self.index < self.length {
+                let byte = self.chunk[self.index];
+                self.index += 1;
+                if let Some(fix) = self.stream.push(byte) {
+                    return Ok(Some(fix));
+                }
+            }
+            self.index = 0;
+            self.length = match self.reader.read(&mut self.chunk) {
+                Ok(0) => return Ok(None),
+                Ok(length) => length,
+                Err(error) if error.kind() == ErrorKind::Interrupted => 0,
+                Err(error) => return Err(error),
+            };
+        }
+    }
+
+    /// Give back the reader. Bytes already read but not yet parsed are lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for LogReader<R> {
+    type Item = Result<GgaFix>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<GgaFix>> {
+        self.next_fix().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_gga_struct;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+    use std::io::{Cursor, Error};
+    use std::vec::Vec;
+
+    /// Source returning at most `limit` bytes per read, interrupted before every other read.
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        limit: usize,
+        interrupted: bool,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            self.interrupted = !self.interrupted;
+            if self.interrupted {
+                return Err(Error::from(ErrorKind::Interrupted));
+            }
+            let length = self.limit.min(buf.len()).min(self.bytes.len());
+            buf[..length].copy_from_slice(&self.bytes[..length]);
+            self.bytes = &self.bytes[length..];
+            Ok(length)
+        }
+    }
+
+    #[test]
+    fn test_log_reader() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_WITH_FIX, 0);
+        let expected_fix = extract_gga_struct(&buffer, 0).unwrap();
+
+        let fixes: Vec<GgaFix> = LogReader::new(Cursor::new(LOG)).map(|fix| fix.unwrap()).collect();
+        assert_eq!(fixes, [expected_fix, expected_fix]);
+
+        for limit in 1..=LOG.len() {
+            let reader = LogReader::new(Trickle {
+                bytes: LOG,
+                limit,
+                interrupted: false,
+            });
+            assert_eq!(reader.collect::<Result<Vec<GgaFix>>>().unwrap(), fixes);
+        }
+    }
+
+    #[test]
+    fn test_log_reader_error() {
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> Result<usize> {
+                Err(Error::from(ErrorKind::UnexpectedEof))
+            }
+        }
+
+        let mut reader = LogReader::new(Failing);
+        assert_eq!(reader.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    }
+
+    const LOG: &[u8] = b"73,M,41.1,M,,*59\r\n\
+        $GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        \xB5\x62\x01\x07\x5C\x00\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGGA,0512$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNGGA,0512";
+}
@@ -0,0 +1,199 @@
+//! Sentence reassembly for bytes that arrive one at a time or in separate receive windows.
+use crate::buffer::NmeaBuffer;
+use crate::checked::extract_gga_slice;
+use crate::fix::GgaFix;
+use crate::is_gga;
+use crate::parse::MAX_SENTENCE_LENGTH;
+use crate::trace::log_debug;
+
+/// Reassembles sentences from bytes that arrive in separate receive windows.
+///
+/// A sentence that is only partly received when a window ends is copied into an internal scratch buffer and
+/// completed by the next window, even if the circular buffer has since been overwritten. Bytes before the first `$`
+/// are ignored, and a `$` always restarts the sentence. Sentences longer than the NMEA 0183 limit are discarded.
+#[derive(Clone, Debug)]
+pub struct Reassembler {
+    sentence: [u8; MAX_SENTENCE_LENGTH],
+    length: usize,
+}
+
+impl Reassembler {
+    /// Create a reassembler waiting for the start of a sentence.
+    #[inline]
+    pub const fn new() -> Reassembler {
+        Reassembler {
+            sentence: [0; MAX_SENTENCE_LENGTH],
+            length: 0,
+        }
+    }
+
+    /// Number of bytes of the partly received sentence carried over.
+    #[inline]
+    pub const fn pending(&self) -> usize {
+        self.length
+    }
+
+    /// Feed one received byte.
+    ///
+    /// ### Returns
+    /// The sentence, including `$` and `\r\n`, when `byte` completes one.
+    #[inline]
+    pub fn push(&mut self, byte: u8) -> Option<&[u8]> {
+        if byte == b'$' {
+            self.sentence[0] = byte;
+            self.length = 1;
+            return None;
+        }
+        if self.length == 0 {
+            // Waiting for the start of a sentence
+            return None;
+        }
+        if self.length == MAX_SENTENCE_LENGTH {
+            // Too long to be a sentence, wait for the next one
+            log_debug!("discarded sentence longer than {} bytes", MAX_SENTENCE_LENGTH);
+            self.length = 0;
+            return None;
+        }
+        self.sentence[self.length] = byte;
+        self.length += 1;
+        if byte != b'\n' {
+            return None;
+        }
+
+        let length = self.length;
+        self.length = 0;
+        Some(&self.sentence[..length])
+    }
+
+    /// Feed a window of a circular buffer, calling `on_sentence` for each sentence it completes.
+    ///
+    /// ### Arguments
+    /// * `buffer` - A circular buffer containing NMEA 0183 data.
+    /// * `window_begin` - Index of the first received byte.
+    /// * `window_len` - Number of received bytes.
+    /// * `on_sentence` - Called with each complete sentence, including `$` and `\r\n`.
+    #[inline]
+    pub fn feed<B: NmeaBuffer + ?Sized, F: FnMut(&[u8])>(
+        &mut self,
+        buffer: &B,
+        window_begin: usize,
+        window_len: usize,
+        mut on_sentence: F,
+    ) {
+        for i in 0..window_len {
+            if let Some(sentence) = self.push(buffer.at(window_begin + i)) {
+                on_sentence(sentence);
+            }
+        }
+    }
+}
+
+impl Default for Reassembler {
+    #[inline]
+    fn default() -> Reassembler {
+        Reassembler::new()
+    }
+}
+
+/// Push-based GGA parser that assembles sentences one byte at a time.
+///
+/// Bytes before the first `$` are ignored, and a `$` always restarts the sentence, so the stream resynchronises
+/// after dropped bytes. Sentences longer than the NMEA 0183 limit are discarded.
+#[derive(Clone, Debug, Default)]
+pub struct GgaStream {
+    reassembler: Reassembler,
+}
+
+impl GgaStream {
+    /// Create a stream waiting for the start of a sentence.
+    #[inline]
+    pub const fn new() -> GgaStream {
+        GgaStream {
+            reassembler: Reassembler::new(),
+        }
+    }
+
+    /// Feed one received byte.
+    ///
+    /// ### Returns
+    /// The fix when `byte` completes a GGA sentence containing one.
+    #[inline]
+    pub fn push(&mut self, byte: u8) -> Option<GgaFix> {
+        let sentence = self.reassembler.push(byte)?;
+        if sentence.len() < 6 || !is_gga(sentence, 0) {
+            return None;
+        }
+        extract_gga_slice(sentence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extract_gga_struct;
+    use crate::tests::{GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_gga_stream() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_WITH_FIX, 0);
+        let expected_fix = extract_gga_struct(&buffer, 0).unwrap();
+
+        let mut stream = GgaStream::new();
+        let mut fixes = 0;
+        for (i, b) in STREAM.iter().enumerate() {
+            if let Some(fix) = stream.push(*b) {
+                assert_eq!(fix, expected_fix);
+                assert_eq!(STREAM[i], b'\n');
+                fixes += 1;
+            }
+        }
+        assert_eq!(fixes, 2);
+    }
+
+    #[test]
+    fn test_gga_stream_overlong() {
+        let mut stream = GgaStream::new();
+        for b in b"$GNGGA,".iter() {
+            assert_eq!(stream.push(*b), None);
+        }
+        for _ in 0..MAX_SENTENCE_LENGTH {
+            assert_eq!(stream.push(b'0'), None);
+        }
+        for b in GGA_WITH_FIX[1..].iter() {
+            assert_eq!(stream.push(*b), None);
+        }
+    }
+
+    #[test]
+    fn test_reassembler_carry_over() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        shift_buffer(&mut buffer, GGA_WITH_FIX, 0);
+        let expected_fix = extract_gga_struct(&buffer, 0).unwrap();
+
+        let mut reassembler = Reassembler::new();
+        let mut fixes = 0;
+        for split in 1..GGA_WITH_FIX.len() {
+            for i in 0..1024 {
+                // The first part is overwritten before the rest arrives
+                shift_buffer(&mut buffer, &GGA_WITH_FIX[..split], i);
+                reassembler.feed(&buffer, i, split, |_| panic!());
+                assert_eq!(reassembler.pending(), split);
+                shift_buffer(&mut buffer, &GGA_WITH_FIX[split..], (i + 512) & 1023);
+                reassembler.feed(&buffer, i + 512, GGA_WITH_FIX.len() - split, |sentence| {
+                    assert_eq!(sentence, GGA_WITH_FIX);
+                    assert_eq!(extract_gga_slice(sentence), Some(expected_fix));
+                    fixes += 1;
+                });
+                assert_eq!(reassembler.pending(), 0);
+            }
+        }
+        assert_eq!(fixes, (GGA_WITH_FIX.len() - 1) * 1024);
+    }
+
+    const STREAM: &[u8] = b"73,M,41.1,M,,*59\r\n\
+        $GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n\
+        $GNRMC,051200.993,A,2734.21973,S,15303.08927,E,0.52,123.45,150625,,,A*67\r\n\
+        $GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n\
+        $GNGGA,0512$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n";
+}
@@ -0,0 +1,144 @@
+//! Trimble TAIP position velocity reports from a GGA sentence.
+use crate::buffer::NmeaBuffer;
+use crate::checksum::checksum;
+use crate::degrees::extract_gga_e7;
+use crate::emit::write_digits;
+use crate::parse::{digit_at, parse_decimal};
+
+/// Length of a `>RPV…;*hh<` report without a vehicle ID.
+pub const TAIP_RPV_LENGTH: usize = 39;
+
+/// Write degrees scaled by 10^7 as a sign and `width` digits of degrees scaled by 10^5.
+#[inline(always)]
+fn write_degrees(out: &mut [u8], degrees_e7: i32, width: usize) {
+    out[0] = if degrees_e7 < 0 { b'-' } else { b'+' };
+    write_digits(&mut out[1..], (degrees_e7.unsigned_abs() + 50) / 100, width);
+}
+
+/// Format a GGA sentence in a circular buffer as a TAIP `>RPV` report.
+///
+/// GGA has no speed or heading, so both are reported as 0. The time is the UTC time of day, as GGA does not give the
+/// GPS-UTC offset. The report layout is:
+/// * `[0..4]` - `>RPV`.
+/// * `[4..9]` - Time of day in seconds.
+/// * `[9..17]` - Latitude as `±DD.DDDDD` without the point.
+/// * `[17..26]` - Longitude as `±DDD.DDDDD` without the point.
+/// * `[26..32]` - Speed in mph and heading in degrees, `000000`.
+/// * `[32]` - Source: `1` for a 3D fix, `3` for a 3D differential fix, `6` for dead reckoning.
+/// * `[33]` - Age of data: `2`, fresh.
+/// * `[34..39]` - `;*`, checksum of `>` through `*`, and `<`.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `report` - Output buffer where the report will be stored (39 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix with a valid time of day. The report is left untouched otherwise.
+#[inline]
+pub fn format_taip_rpv<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    report: &mut [u8; TAIP_RPV_LENGTH],
+) -> bool {
+    let mut position_block = [0; 9];
+    if !extract_gga_e7(buffer, sentence_begin, &mut position_block) {
+        return false;
+    }
+    let lat = i32::from_be_bytes([
+        position_block[0],
+        position_block[1],
+        position_block[2],
+        position_block[3],
+    ]);
+    let lon = i32::from_be_bytes([
+        position_block[4],
+        position_block[5],
+        position_block[6],
+        position_block[7],
+    ]);
+
+    let hours = digit_at(buffer, sentence_begin + 7) * 10 + digit_at(buffer, sentence_begin + 8);
+    let minutes = digit_at(buffer, sentence_begin + 9) * 10 + digit_at(buffer, sentence_begin + 10);
+    let (seconds, _) = parse_decimal(buffer, sentence_begin + 11, 0);
+    let time = (hours * 60 + minutes).saturating_mul(60).saturating_add(seconds);
+    // Up to 23:59:60 for a leap second, the rest does not fit the 5-digit field
+    if time > 86_400 {
+        return false;
+    }
+
+    report[0..4].copy_from_slice(b">RPV");
+    write_digits(&mut report[4..9], time, 5);
+    write_degrees(&mut report[9..17], lat, 7);
+    write_degrees(&mut report[17..26], lon, 8);
+    report[26..32].copy_from_slice(b"000000");
+    report[32] = match digit_at(buffer, sentence_begin + 45) {
+        2 => b'3',
+        6 => b'6',
+        _ => b'1',
+    };
+    report[33] = b'2';
+    report[34..36].copy_from_slice(b";*");
+    let digits = checksum(&report[..36]);
+    report[36..38].copy_from_slice(&digits);
+    report[38] = b'<';
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_NO_FIX, shift_buffer};
+
+    #[test]
+    fn test_taip_rpv_no_fix() {
+        let mut report = [0; TAIP_RPV_LENGTH];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert!(!format_taip_rpv(&buffer, i, &mut report));
+            assert_eq!(report, [0; TAIP_RPV_LENGTH]);
+        }
+    }
+
+    #[test]
+    fn test_taip_rpv_invalid_time() {
+        let mut report = [0; TAIP_RPV_LENGTH];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for sentence in [
+            &b"$GNGGA,05129999999999,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n"[..],
+            &b"$GNGGA,991200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n"[..],
+        ] {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(!format_taip_rpv(&buffer, i, &mut report));
+                assert_eq!(report, [0; TAIP_RPV_LENGTH]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_taip_rpv_with_fix() {
+        let mut report = [0; TAIP_RPV_LENGTH];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_report) in RPV.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert!(format_taip_rpv(&buffer, i, &mut report));
+                assert_eq!(report, **expected_report);
+            }
+        }
+    }
+
+    const RPV: [(&[u8], &[u8; TAIP_RPV_LENGTH]); 2] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            b">RPV18720-2757033+1530514900000012;*7B<",
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            b">RPV65701+3974168-1049861100000032;*7D<",
+        ),
+    ];
+}
@@ -0,0 +1,100 @@
+//! Talker ID identification.
+use crate::buffer::NmeaBuffer;
+use crate::is_gga;
+
+/// Talker ID of a sentence, identifying the constellations that produced it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Talker {
+    /// `GP`: GPS.
+    Gps,
+    /// `GN`: Combined GNSS solution.
+    Gnss,
+    /// `GL`: GLONASS.
+    Glonass,
+    /// `GA`: Galileo.
+    Galileo,
+    /// `GB`: BeiDou.
+    BeiDou,
+    /// `BD`: BeiDou, used by older receivers.
+    BeiDouLegacy,
+    /// `GQ`: QZSS.
+    Qzss,
+    /// `GI`: NavIC.
+    NavIc,
+    Unknown,
+}
+
+impl Talker {
+    /// Identify a talker from its two character ID, e.g. `GN`.
+    #[inline]
+    pub const fn from_id(id: [u8; 2]) -> Talker {
+        match &id {
+            b"GP" => Talker::Gps,
+            b"GN" => Talker::Gnss,
+            b"GL" => Talker::Glonass,
+            b"GA" => Talker::Galileo,
+            b"GB" => Talker::BeiDou,
+            b"BD" => Talker::BeiDouLegacy,
+            b"GQ" => Talker::Qzss,
+            b"GI" => Talker::NavIc,
+            _ => Talker::Unknown,
+        }
+    }
+}
+
+/// Read the talker ID of a sentence.
+#[inline]
+pub fn talker<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> Talker {
+    Talker::from_id([buffer.at(sentence_begin + 1), buffer.at(sentence_begin + 2)])
+}
+
+/// Determine if the sentence is a GGA sentence, and which talker sent it.
+///
+/// ### Returns
+/// The talker if the sentence is a GGA sentence.
+#[inline]
+pub fn gga_talker<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> Option<Talker> {
+    if is_gga(buffer, sentence_begin) {
+        Some(talker(buffer, sentence_begin))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_gga_talker() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_talker) in TALKERS.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(talker(&buffer, i), *expected_talker);
+                let expected_gga_talker = if sentence[3..6] == *b"GGA" {
+                    Some(*expected_talker)
+                } else {
+                    None
+                };
+                assert_eq!(gga_talker(&buffer, i), expected_gga_talker);
+            }
+        }
+    }
+
+    const TALKERS: [(&[u8], Talker); 6] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            Talker::Gnss,
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,1,03,2.10,84.6,M,41.1,M,,*6E\r\n",
+            Talker::Gps,
+        ),
+        (b"$GLGGA,051154.000,,,,,0,00,25.5,,,,,,*7C\r\n", Talker::Glonass),
+        (b"$BDGGA,051154.000,,,,,0,00,25.5,,,,,,*71\r\n", Talker::BeiDouLegacy),
+        (b"$GBGSA,A,3,,,,,,,,,,,,,25.5,25.5,25.5,4*0A\r\n", Talker::BeiDou),
+        (b"$PUBX,00,051154.000,,,,,,*05\r\n", Talker::Unknown),
+    ];
+}
@@ -0,0 +1,81 @@
+//! Packed UTC time of day and date for the sentence generators.
+
+/// UTC time of day in milliseconds since midnight, as in the extension block of
+/// [`extract_gga_ext`](crate::extract_gga_ext).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PackedTime(pub u32);
+
+impl PackedTime {
+    /// Pack a time of day.
+    #[inline]
+    pub const fn new(hour: u8, minute: u8, second: u8, millisecond: u16) -> PackedTime {
+        PackedTime(((hour as u32 * 60 + minute as u32) * 60 + second as u32) * 1000 + millisecond as u32)
+    }
+
+    /// Hour, 0 to 23.
+    #[inline]
+    pub const fn hour(self) -> u8 {
+        (self.0 / 3_600_000 % 24) as u8
+    }
+
+    /// Minute, 0 to 59.
+    #[inline]
+    pub const fn minute(self) -> u8 {
+        (self.0 / 60_000 % 60) as u8
+    }
+
+    /// Second, 0 to 59.
+    #[inline]
+    pub const fn second(self) -> u8 {
+        (self.0 / 1000 % 60) as u8
+    }
+
+    /// Millisecond, 0 to 999.
+    #[inline]
+    pub const fn millisecond(self) -> u16 {
+        (self.0 % 1000) as u16
+    }
+}
+
+/// UTC calendar date.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PackedDate {
+    /// Year, e.g. 2025.
+    pub year: u16,
+    /// Month, 1 to 12.
+    pub month: u8,
+    /// Day of the month, 1 to 31.
+    pub day: u8,
+}
+
+impl PackedDate {
+    /// Pack a date.
+    #[inline]
+    pub const fn new(year: u16, month: u8, day: u8) -> PackedDate {
+        PackedDate { year, month, day }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packed_time() {
+        let time = PackedTime::new(5, 12, 0, 993);
+        assert_eq!(time, PackedTime(18_720_993));
+        assert_eq!(
+            (time.hour(), time.minute(), time.second(), time.millisecond()),
+            (5, 12, 0, 993)
+        );
+
+        let time = PackedTime::new(23, 59, 59, 999);
+        assert_eq!(
+            (time.hour(), time.minute(), time.second(), time.millisecond()),
+            (23, 59, 59, 999)
+        );
+        assert_eq!(PackedTime(time.0 + 1).hour(), 0);
+    }
+}
@@ -0,0 +1,39 @@
+//! Parse event logging through the `log` crate, compiled out without the `log` feature.
+
+/// Log a trace event, e.g. for every framed sentence.
+#[cfg(feature = "log")]
+macro_rules! log_trace {
+    ($($arg:tt)*) => {
+        ::log::trace!($($arg)*)
+    };
+}
+
+/// Type-check the arguments without emitting anything, so they never read as unused.
+#[cfg(not(feature = "log"))]
+macro_rules! log_trace {
+    ($($arg:tt)*) => {
+        if false {
+            let _ = ::core::format_args!($($arg)*);
+        }
+    };
+}
+
+/// Log a debug event, e.g. for a checksum failure or discarded fix.
+#[cfg(feature = "log")]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        ::log::debug!($($arg)*)
+    };
+}
+
+/// Type-check the arguments without emitting anything, so they never read as unused.
+#[cfg(not(feature = "log"))]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        if false {
+            let _ = ::core::format_args!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use {log_debug, log_trace};
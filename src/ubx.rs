@@ -0,0 +1,158 @@
+//! u-blox UBX-NAV-PVT frames from a GGA sentence, for navigation stacks that only consume UBX.
+use crate::buffer::NmeaBuffer;
+use crate::degrees::extract_gga_e7;
+use crate::parse::{digit_at, parse_decimal, parse_signed_decimal, skip_field};
+
+/// Length of the UBX-NAV-PVT payload.
+const NAV_PVT_PAYLOAD_LENGTH: usize = 92;
+
+/// Length of a UBX-NAV-PVT frame: sync, class, ID, length, payload and checksum.
+pub const NAV_PVT_LENGTH: usize = NAV_PVT_PAYLOAD_LENGTH + 8;
+
+/// Fill a UBX-NAV-PVT frame from a GGA sentence in a circular buffer.
+///
+/// GGA carries only part of NAV-PVT, so the frame is filled as follows:
+/// * `iTOW`, date, velocities and headings are 0, and `valid` only sets `validTime`.
+/// * `hour`, `min`, `sec` and `nano` are the UTC time of the fix.
+/// * `fixType` is 3D, `gnssFixOK` is set, `diffSoln` is set for DGPS and `carrSoln` follows RTK float or fixed.
+/// * `height` is the altitude plus geoid separation, `hMSL` the altitude, both in millimetres.
+/// * `pDOP` is the HDOP, as GGA has no PDOP.
+/// * Accuracy estimates are `0xFFFFFFFF`, unknown.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `frame` - Output buffer where the complete UBX frame will be stored (100 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix. The frame is left untouched otherwise.
+#[inline]
+pub fn extract_gga_nav_pvt<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    frame: &mut [u8; NAV_PVT_LENGTH],
+) -> bool {
+    let mut position_block = [0; 9];
+    if !extract_gga_e7(buffer, sentence_begin, &mut position_block) {
+        return false;
+    }
+
+    // Hdop, altitude and separation, skipping the altitude units
+    let (hdop, end) = parse_decimal(buffer, sentence_begin + 50, 2);
+    let (altitude, end) = parse_signed_decimal(buffer, skip_field(buffer, end), 1);
+    let (separation, _) = parse_signed_decimal(buffer, skip_field(buffer, skip_field(buffer, end)), 1);
+    let h_msl = altitude.saturating_mul(100);
+    let height = altitude.saturating_add(separation).saturating_mul(100);
+
+    let (millis, _) = parse_decimal(buffer, sentence_begin + 11, 3);
+    let quality = digit_at(buffer, sentence_begin + 45);
+    let flags = match quality {
+        // gnssFixOK, diffSoln
+        2 => 0b0000_0011,
+        // gnssFixOK, carrSoln fixed
+        4 => 0b1000_0001,
+        // gnssFixOK, carrSoln float
+        5 => 0b0100_0001,
+        _ => 0b0000_0001,
+    };
+
+    frame.fill(0);
+    frame[0] = 0xB5;
+    frame[1] = 0x62;
+    // NAV class, PVT ID
+    frame[2] = 0x01;
+    frame[3] = 0x07;
+    frame[4] = NAV_PVT_PAYLOAD_LENGTH as u8;
+
+    let payload = &mut frame[6..6 + NAV_PVT_PAYLOAD_LENGTH];
+    payload[8] = (digit_at(buffer, sentence_begin + 7) * 10 + digit_at(buffer, sentence_begin + 8)) as u8;
+    payload[9] = (digit_at(buffer, sentence_begin + 9) * 10 + digit_at(buffer, sentence_begin + 10)) as u8;
+    payload[10] = (millis / 1000) as u8;
+    // validTime
+    payload[11] = 0b0000_0010;
+    payload[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+    payload[16..20].copy_from_slice(&((millis % 1000) as i32 * 1_000_000).to_le_bytes());
+    // 3D fix
+    payload[20] = 3;
+    payload[21] = flags;
+    payload[23] = (digit_at(buffer, sentence_begin + 47) * 10 + digit_at(buffer, sentence_begin + 48)) as u8;
+    // Longitude and latitude are little-endian and in the opposite order
+    payload[24..28].copy_from_slice(&[
+        position_block[7],
+        position_block[6],
+        position_block[5],
+        position_block[4],
+    ]);
+    payload[28..32].copy_from_slice(&[
+        position_block[3],
+        position_block[2],
+        position_block[1],
+        position_block[0],
+    ]);
+    payload[32..36].copy_from_slice(&height.to_le_bytes());
+    payload[36..40].copy_from_slice(&h_msl.to_le_bytes());
+    payload[40..44].copy_from_slice(&u32::MAX.to_le_bytes());
+    payload[44..48].copy_from_slice(&u32::MAX.to_le_bytes());
+    payload[68..72].copy_from_slice(&u32::MAX.to_le_bytes());
+    payload[72..76].copy_from_slice(&u32::MAX.to_le_bytes());
+    let pdop = if hdop < 65536 { hdop as u16 } else { 65535 };
+    payload[76..78].copy_from_slice(&pdop.to_le_bytes());
+
+    // 8-bit Fletcher checksum over class, ID, length and payload
+    let mut ck_a: u8 = 0;
+    let mut ck_b: u8 = 0;
+    for &b in frame[2..NAV_PVT_LENGTH - 2].iter() {
+        ck_a = ck_a.wrapping_add(b);
+        ck_b = ck_b.wrapping_add(ck_a);
+    }
+    frame[NAV_PVT_LENGTH - 2] = ck_a;
+    frame[NAV_PVT_LENGTH - 1] = ck_b;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_NO_FIX, shift_buffer};
+
+    fn read_i32(frame: &[u8; NAV_PVT_LENGTH], offset: usize) -> i32 {
+        i32::from_le_bytes(frame[6 + offset..10 + offset].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_nav_pvt_no_fix() {
+        let mut frame = [0xAA; NAV_PVT_LENGTH];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert!(!extract_gga_nav_pvt(&buffer, i, &mut frame));
+            assert_eq!(frame, [0xAA; NAV_PVT_LENGTH]);
+        }
+    }
+
+    #[test]
+    fn test_nav_pvt_with_fix() {
+        let mut frame = [0; NAV_PVT_LENGTH];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            assert!(extract_gga_nav_pvt(&buffer, i, &mut frame));
+            assert_eq!(frame[..6], [0xB5, 0x62, 0x01, 0x07, 92, 0]);
+            // hour, min, sec, valid
+            assert_eq!(frame[14..18], [18, 15, 1, 0b10]);
+            assert_eq!(read_i32(&frame, 16), 0);
+            // fixType, flags, flags2, numSV
+            assert_eq!(frame[26..30], [3, 0b11, 0, 3]);
+            assert_eq!(read_i32(&frame, 24), -1049861090);
+            assert_eq!(read_i32(&frame, 28), 397416810);
+            assert_eq!(read_i32(&frame, 32), -41800);
+            assert_eq!(read_i32(&frame, 36), -12300);
+            assert_eq!(frame[82..84], 210u16.to_le_bytes());
+            assert_eq!(frame[98..100], [27, 194]);
+        }
+    }
+
+    const GGA_WITH_FIX: &[u8] =
+        b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n";
+}
@@ -0,0 +1,401 @@
+//! Sanity checks for GGA sentences, to run before or alongside extraction.
+use crate::buffer::NmeaBuffer;
+use crate::checked::{GGA_FIXED_SPAN, GgaError};
+use crate::checksum::hex_value;
+use crate::extract_gga;
+use crate::field::Fields;
+use crate::parse::{MAX_SENTENCE_LENGTH, parse_decimal};
+
+/// Length of the shortest GGA sentence with a fix in the fixed-offset layout of [`extract_gga`]: single digit HDOP,
+/// empty altitude, geoid separation and differential fields, checksum and `\r\n`.
+pub const GGA_MIN_FIX_LENGTH: usize = 64;
+
+/// Determine if a sentence is long enough to hold a GGA fix, before [`extract_gga`] reads at fixed offsets.
+///
+/// A truncated sentence would otherwise have its missing fields read from whatever follows it in the buffer.
+///
+/// ### Arguments
+/// * `sentence_len` - Length of the sentence, as from [`calculate_sentence_length`](crate::calculate_sentence_length).
+#[inline]
+pub const fn gga_length_ok(sentence_len: usize) -> bool {
+    sentence_len >= GGA_MIN_FIX_LENGTH && sentence_len <= MAX_SENTENCE_LENGTH
+}
+
+/// Find the `*hh\r\n` terminator of a sentence within the NMEA 0183 length limit.
+///
+/// A `$` before the terminator means the sentence was cut short and what follows belongs to another one, such as a
+/// stale tail left in the buffer.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the sentence in the buffer.
+///
+/// ### Returns
+/// The length of the sentence including `\r\n`, if it is properly terminated.
+#[inline]
+pub fn terminated_length<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> Option<usize> {
+    // The checksum and line ending take 5 bytes
+    for i in 1..=MAX_SENTENCE_LENGTH - 5 {
+        match buffer.at(sentence_begin + i) {
+            b'*' => {
+                let terminated = hex_value(buffer.at(sentence_begin + i + 1)).is_some()
+                    && hex_value(buffer.at(sentence_begin + i + 2)).is_some()
+                    && buffer.at(sentence_begin + i + 3) == b'\r'
+                    && buffer.at(sentence_begin + i + 4) == b'\n';
+                return if terminated { Some(i + 5) } else { None };
+            }
+            b'$' | b'\r' | b'\n' => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Delimiters of the fixed-offset GGA layout up to the HDOP field, as offsets from `$`.
+const GGA_DELIMITERS: [(usize, u8); 11] = [
+    (6, b','),
+    (13, b'.'),
+    (17, b','),
+    (22, b'.'),
+    (28, b','),
+    (30, b','),
+    (36, b'.'),
+    (42, b','),
+    (44, b','),
+    (46, b','),
+    (49, b','),
+];
+
+/// Check that a GGA sentence has the comma and decimal point positions and field count [`extract_gga`] relies on.
+///
+/// Catches receivers configured with a different time or coordinate precision, which would otherwise be misparsed.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+///
+/// ### Returns
+/// Where the layout first deviates, or [`GgaError::NoTime`] or [`GgaError::NoFix`] if there is no position to check.
+#[inline]
+pub fn check_gga_layout<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> Result<(), GgaError> {
+    if buffer.at(sentence_begin + 7) == b',' {
+        return Err(GgaError::NoTime);
+    }
+    if buffer.at(sentence_begin + 18) == b',' {
+        return Err(GgaError::NoFix);
+    }
+    for (offset, delimiter) in GGA_DELIMITERS {
+        if buffer.at(sentence_begin + offset) != delimiter {
+            return Err(GgaError::InvalidDelimiter(offset));
+        }
+    }
+    // HDOP has one or two integer digits
+    if buffer.at(sentence_begin + 51) != b'.' && buffer.at(sentence_begin + 52) != b'.' {
+        return Err(GgaError::InvalidDelimiter(51));
+    }
+    let field_count = Fields::new(buffer, sentence_begin, MAX_SENTENCE_LENGTH).count();
+    if field_count != 15 {
+        return Err(GgaError::InvalidFieldCount(field_count));
+    }
+    Ok(())
+}
+
+/// Determine if the latitude and longitude of a GGA sentence are within ±90° and ±180° with minutes below 60.
+///
+/// The check reads the fields rather than the position block, because latitudes above about 42.9° do not fit the
+/// block's 32-bit `ddmm.mmmmm` encoding and would wrap into range. Relies on the same fixed-offset layout as
+/// [`extract_gga`].
+#[inline]
+pub fn gga_position_in_range<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    let (lat, _) = parse_decimal(buffer, sentence_begin + 18, 5);
+    let lat_degrees = lat / 10_000_000;
+    let lat_minutes = lat % 10_000_000;
+    let (lon, _) = parse_decimal(buffer, sentence_begin + 31, 5);
+    let lon_degrees = lon / 10_000_000;
+    let lon_minutes = lon % 10_000_000;
+
+    lat_minutes < 6_000_000
+        && lon_minutes < 6_000_000
+        && (lat_degrees < 90 || (lat_degrees == 90 && lat_minutes == 0))
+        && (lon_degrees < 180 || (lon_degrees == 180 && lon_minutes == 0))
+}
+
+/// Extract position data from a GGA sentence as [`extract_gga`] does, treating an out of range position as no fix.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix within range, see [`gga_position_in_range`]. The position block is left
+/// untouched otherwise.
+#[inline]
+pub fn extract_gga_in_range<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 10],
+) -> bool {
+    // Check the time and latitude fields are present before reading them
+    if buffer.at(sentence_begin + 7) == b',' || buffer.at(sentence_begin + 18) == b',' {
+        return false;
+    }
+    gga_position_in_range(buffer, sentence_begin) && extract_gga(buffer, sentence_begin, position_block)
+}
+
+/// Extract position data from a GGA sentence as [`extract_gga`] does, detecting if the DMA overwrote it meanwhile.
+///
+/// NDTR is read before and after parsing. The sentence may be torn if the DMA write position was inside the bytes
+/// read when parsing started, or advanced into them before it finished.
+///
+/// ### Arguments
+/// * `buffer` - The circular buffer the DMA is writing to.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `position_block` - Output buffer where parsed position data will be stored (10 bytes).
+/// * `read_ndtr` - Reads the current value of the DMA NDTR register.
+///
+/// ### Returns
+/// If the sentence contains a GNSS fix, or [`GgaError::Torn`] if the position block should be discarded, including
+/// when an NDTR reading exceeds the buffer length. An empty buffer contains no fix.
+#[inline]
+pub fn extract_gga_untorn<B: NmeaBuffer + ?Sized, F: FnMut() -> u16>(
+    buffer: &B,
+    sentence_begin: usize,
+    position_block: &mut [u8; 10],
+    mut read_ndtr: F,
+) -> Result<bool, GgaError> {
+    let len = buffer.len();
+    if len == 0 {
+        return Ok(false);
+    }
+    // A spurious reading beyond the buffer leaves the write position unknown
+    let write_index_before = len.checked_sub(read_ndtr() as usize).ok_or(GgaError::Torn)? % len;
+    let parsed = extract_gga(buffer, sentence_begin, position_block);
+    let write_index_after = len.checked_sub(read_ndtr() as usize).ok_or(GgaError::Torn)? % len;
+
+    let begin = sentence_begin % len;
+    // Bytes the DMA had to write before reaching the sentence
+    let headroom = (begin + len - write_index_before) % len;
+    let written = (write_index_after + len - write_index_before) % len;
+    let inside = (write_index_before + len - begin) % len < GGA_FIXED_SPAN;
+    if inside || written > headroom {
+        return Err(GgaError::Torn);
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_gga_length_ok() {
+        assert!(gga_length_ok(
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,,,,,,*65\r\n".len()
+        ));
+        assert!(gga_length_ok(
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n".len()
+        ));
+        assert!(!gga_length_ok(
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,,,,,,*".len()
+        ));
+        assert!(!gga_length_ok(b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n".len()));
+        assert!(!gga_length_ok(MAX_SENTENCE_LENGTH + 1));
+    }
+
+    #[test]
+    fn test_terminated_length() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_length) in TERMINATED.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(terminated_length(&buffer, i), *expected_length);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_gga_untorn() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let sentence = GGA_RANGE[0].0;
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, sentence, i);
+            let end = (i + sentence.len()) & 1023;
+            let mut expected_position_block = [0; 10];
+            assert!(extract_gga(&buffer, i, &mut expected_position_block));
+            for (before, after, expected_torn) in UNTORN.iter() {
+                let mut ndtr = [
+                    (1024 - ((end + before) & 1023)) as u16,
+                    (1024 - ((end + after) & 1023)) as u16,
+                ]
+                .into_iter();
+                let mut position_block = [0; 10];
+                let result = extract_gga_untorn(&buffer, i, &mut position_block, || ndtr.next().unwrap());
+                if *expected_torn {
+                    assert_eq!(result, Err(GgaError::Torn));
+                } else {
+                    assert_eq!(result, Ok(true));
+                    assert_eq!(position_block, expected_position_block);
+                }
+            }
+
+            // Readings beyond the buffer length, before or after parsing
+            for ndtr in [[1025, 1024], [1024, 1025], [u16::MAX, u16::MAX]] {
+                let mut ndtr = ndtr.into_iter();
+                let mut position_block = [0; 10];
+                let result = extract_gga_untorn(&buffer, i, &mut position_block, || ndtr.next().unwrap());
+                assert_eq!(result, Err(GgaError::Torn));
+            }
+        }
+        assert_eq!(extract_gga_untorn(&[][..], 0, &mut [0; 10], || 0), Ok(false));
+    }
+
+    #[test]
+    fn test_check_gga_layout() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_result) in GGA_LAYOUT.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(check_gga_layout(&buffer, i), *expected_result);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gga_position_in_range() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_in_range) in GGA_RANGE.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                assert_eq!(gga_position_in_range(&buffer, i), *expected_in_range);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_gga_in_range() {
+        let mut expected_position_block = [0; 10];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_in_range) in GGA_RANGE.iter() {
+            // Latitudes above 42.9 degrees do not fit the position block
+            if *expected_in_range && sentence[18] >= b'4' {
+                continue;
+            }
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let mut position_block = [0; 10];
+                assert_eq!(
+                    extract_gga_in_range(&buffer, i, &mut position_block),
+                    *expected_in_range
+                );
+                if *expected_in_range {
+                    extract_gga(&buffer, i, &mut expected_position_block);
+                    assert_eq!(position_block, expected_position_block);
+                } else {
+                    assert_eq!(position_block, [0; 10]);
+                }
+            }
+        }
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n", i);
+            assert!(!extract_gga_in_range(&buffer, i, &mut expected_position_block));
+        }
+    }
+
+    /// Write positions before and after parsing, relative to the end of the sentence, and if the sentence is torn.
+    const UNTORN: [(usize, usize, bool); 6] = [
+        (0, 0, false),
+        (0, 100, false),
+        (500, 949, false),
+        // Laps around into the sentence during parsing
+        (500, 950, true),
+        (0, 1023, true),
+        // Sentence still being received
+        (1000, 1010, true),
+    ];
+
+    const TERMINATED: [(&[u8], Option<usize>); 6] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            Some(75),
+        ),
+        (b"$HEHDT,5.1,T*2b\r\n$", Some(17)),
+        // Stale tail of an older sentence after a truncated one
+        (
+            b"$GNGGA,051200.993,2734.2$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            None,
+        ),
+        (b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r$", None),
+        (b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,\r\n*7E\r\n", None),
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,,,,,,,,,,*59\r\n",
+            None,
+        ),
+    ];
+
+    const GGA_LAYOUT: [(&[u8], Result<(), GgaError>); 8] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            Ok(()),
+        ),
+        (
+            b"$GPGGA,181501.000,3944.50086,N,10459.16654,W,2,03,2.10,-12.3,M,-29.5,M,3.2,0123*68\r\n",
+            Ok(()),
+        ),
+        (
+            b"$GNGGA,181501.000,3615.12012,S,06357.25158,W,1,03,39.9,84.6,M,41.1,M,,*42\r\n",
+            Ok(()),
+        ),
+        (b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n", Err(GgaError::NoFix)),
+        // Two decimal places in the time
+        (
+            b"$GNGGA,051200.99,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*6A\r\n",
+            Err(GgaError::InvalidDelimiter(17)),
+        ),
+        // Four decimal places in the coordinates
+        (
+            b"$GNGGA,051200.993,2734.2197,S,15303.0892,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            Err(GgaError::InvalidDelimiter(28)),
+        ),
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2,8,103.4,M,41.1,M,,*59\r\n",
+            Err(GgaError::InvalidDelimiter(51)),
+        ),
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M*59\r\n",
+            Err(GgaError::InvalidFieldCount(13)),
+        ),
+    ];
+
+    const GGA_RANGE: [(&[u8], bool); 7] = [
+        (
+            b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            true,
+        ),
+        (
+            b"$GNGGA,051200.993,9000.00000,N,18000.00000,W,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            true,
+        ),
+        (
+            b"$GNGGA,051200.993,8959.99999,N,17959.99999,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            true,
+        ),
+        (
+            b"$GNGGA,051200.993,9000.00001,N,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            false,
+        ),
+        (
+            b"$GNGGA,051200.993,2734.21973,S,18000.00001,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            false,
+        ),
+        (
+            b"$GNGGA,051200.993,2760.00000,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            false,
+        ),
+        (
+            b"$GNGGA,051200.993,2734.21973,S,95303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+            false,
+        ),
+    ];
+}
@@ -0,0 +1,108 @@
+//! Detect and parse VHW (Water Speed and Heading) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Bit set in the [`extract_vhw`] mask if the true heading is present.
+pub const VHW_TRUE_HEADING: u8 = 1 << 0;
+/// Bit set in the [`extract_vhw`] mask if the magnetic heading is present.
+pub const VHW_MAGNETIC_HEADING: u8 = 1 << 1;
+/// Bit set in the [`extract_vhw`] mask if the speed through water is present.
+pub const VHW_SPEED: u8 = 1 << 2;
+
+/// Determine if the sentence is a VHW sentence.
+#[inline]
+pub fn is_vhw<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'V'
+        && byte_at(buffer, sentence_begin + 4) == b'H'
+        && byte_at(buffer, sentence_begin + 5) == b'W'
+}
+
+/// Extract speed through water and heading from a VHW sentence in a circular buffer.
+///
+/// The water block layout is:
+/// * `[0..2]` - True heading in hundredths of a degree, big-endian.
+/// * `[2..4]` - Magnetic heading in hundredths of a degree, big-endian.
+/// * `[4..6]` - Speed through water in hundredths of a knot, big-endian.
+///
+/// Values whose field is empty are written as 0.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the VHW sentence in the buffer.
+/// * `water_block` - Output buffer where parsed data will be stored (6 bytes).
+///
+/// ### Returns
+/// A mask of [`VHW_TRUE_HEADING`], [`VHW_MAGNETIC_HEADING`] and [`VHW_SPEED`] for the values that were present.
+#[inline]
+pub fn extract_vhw<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, water_block: &mut [u8; 6]) -> u8 {
+    let mut mask = 0;
+
+    let index = sentence_begin + 7;
+    mask |= (byte_at(buffer, index) != b',') as u8 * VHW_TRUE_HEADING;
+    let (true_heading, end) = parse_decimal(buffer, index, 2);
+    water_block[0] = (true_heading >> 8) as u8;
+    water_block[1] = true_heading as u8;
+
+    // Skip T
+    let index = skip_field(buffer, skip_field(buffer, end));
+    mask |= (byte_at(buffer, index) != b',') as u8 * VHW_MAGNETIC_HEADING;
+    let (magnetic_heading, end) = parse_decimal(buffer, index, 2);
+    water_block[2] = (magnetic_heading >> 8) as u8;
+    water_block[3] = magnetic_heading as u8;
+
+    // Skip M
+    let index = skip_field(buffer, skip_field(buffer, end));
+    mask |= (byte_at(buffer, index) != b',') as u8 * VHW_SPEED;
+    let (speed, _) = parse_decimal(buffer, index, 2);
+    let speed = if speed < 65536 { speed as u16 } else { 65535 };
+    water_block[4] = (speed >> 8) as u8;
+    water_block[5] = speed as u8;
+
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_vhw() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, VHW[0].0, i);
+            assert!(!is_vhw(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_vhw(&buffer, i));
+            assert!(!is_vhw(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_vhw() {
+        let mut water_block = [0; 6];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_water_block, expected_mask) in VHW.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let mask = extract_vhw(&buffer, i, &mut water_block);
+                assert_eq!(mask, *expected_mask);
+                assert_eq!(water_block, *expected_water_block);
+            }
+        }
+    }
+
+    const VHW: [(&[u8], [u8; 6], u8); 4] = [
+        (
+            b"$VWVHW,245.1,T,232.5,M,5.62,N,10.41,K*65\r\n",
+            [95, 190, 90, 210, 2, 50],
+            VHW_TRUE_HEADING | VHW_MAGNETIC_HEADING | VHW_SPEED,
+        ),
+        (b"$VWVHW,,T,,M,3.10,N,5.74,K*50\r\n", [0, 0, 0, 0, 1, 54], VHW_SPEED),
+        (
+            b"$IIVHW,,T,012.0,M,,N,,K*78\r\n",
+            [0, 0, 4, 176, 0, 0],
+            VHW_MAGNETIC_HEADING,
+        ),
+        (b"$VWVHW,,T,,M,,N,,K*54\r\n", [0; 6], 0),
+    ];
+}
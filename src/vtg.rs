@@ -0,0 +1,114 @@
+//! Detect and parse VTG (Course Over Ground and Ground Speed) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a VTG sentence.
+#[inline]
+pub fn is_vtg<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'V'
+        && byte_at(buffer, sentence_begin + 4) == b'T'
+        && byte_at(buffer, sentence_begin + 5) == b'G'
+}
+
+/// Extract course and speed data from a VTG sentence in a circular buffer.
+///
+/// The course block layout is:
+/// * `[0..2]` - True course over ground in hundredths of a degree, big-endian. 0 if the field is empty.
+/// * `[2..4]` - Speed over ground in centimetres per second, big-endian, derived from the km/h field.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the VTG sentence in the buffer.
+/// * `course_block` - Output buffer where parsed data will be stored (4 bytes).
+///
+/// ### Returns
+/// If the sentence contains a valid course and speed. The block is left untouched otherwise.
+#[inline]
+pub fn extract_vtg<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, course_block: &mut [u8; 4]) -> bool {
+    // True course
+    let (course, end) = parse_decimal(buffer, sentence_begin + 7, 2);
+
+    // Skip T, magnetic course, M, speed in knots and N
+    let mut index = end;
+    for _ in 0..6 {
+        index = skip_field(buffer, index);
+    }
+
+    // Speed in km/h
+    if byte_at(buffer, index) == b',' {
+        // No speed field, no fix
+        return false;
+    }
+    let (speed, end) = parse_decimal(buffer, index, 2);
+
+    // Skip K, the mode indicator is only present from NMEA 2.3
+    let index = skip_field(buffer, skip_field(buffer, end));
+    if byte_at(buffer, index) == b'N' {
+        return false;
+    }
+
+    course_block[0] = (course >> 8) as u8;
+    course_block[1] = course as u8;
+
+    // Convert hundredths of km/h to cm/s, rounded
+    let speed = (speed as u64 * 5 + 9) / 18;
+    let speed = if speed < 65536 { speed as u16 } else { 65535 };
+    course_block[2] = (speed >> 8) as u8;
+    course_block[3] = speed as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_vtg() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, VTG_VALID[0].0, i);
+            assert!(!is_vtg(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_vtg(&buffer, i));
+            assert!(!is_vtg(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_vtg_no_fix() {
+        let mut course_block = [0; 4];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, VTG_NO_FIX, i);
+            let parsed = extract_vtg(&buffer, i, &mut course_block);
+            assert!(!parsed);
+            assert_eq!(course_block, [0; 4]);
+        }
+    }
+
+    #[test]
+    fn test_vtg_valid() {
+        let mut course_block = [0; 4];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_course_block) in VTG_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_vtg(&buffer, i, &mut course_block);
+                assert!(parsed);
+                assert_eq!(course_block, *expected_course_block);
+            }
+        }
+    }
+
+    const VTG_NO_FIX: &[u8] = b"$GNVTG,,T,,M,0.00,N,0.00,K,N*32\r\n";
+
+    const VTG_VALID: [(&[u8], [u8; 4]); 4] = [
+        (b"$GNVTG,123.45,T,,M,0.52,N,0.96,K,A*2A\r\n", [48, 57, 0, 27]),
+        (b"$GPVTG,5.3,T,358.1,M,12.7,N,23.5,K,D*2F\r\n", [2, 18, 2, 141]),
+        // NMEA 2.2 and earlier, no mode indicator
+        (b"$GPVTG,359.99,T,,M,100.00,N,185.20,K*50\r\n", [140, 159, 20, 24]),
+        // Speed saturates instead of overflowing the conversion
+        (b"$GNVTG,123.45,T,,M,0.52,N,99999999.99,K,A*2A\r\n", [48, 57, 255, 255]),
+    ];
+}
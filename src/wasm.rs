@@ -0,0 +1,98 @@
+//! JavaScript bindings through wasm-bindgen, so a web-based simulator runs the same parser as the firmware.
+use crate::degrees::extract_gga_e7;
+use crate::extended::{ExtendedFix, decode_extended_block, extract_gga_extended};
+use crate::is_gga;
+use wasm_bindgen::prelude::*;
+
+/// Fix extracted from a GGA sentence, with JavaScript number fields.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JsFix {
+    /// Latitude in degrees, negative if south.
+    pub lat: f64,
+    /// Longitude in degrees, negative if west.
+    pub lon: f64,
+    /// Horizontal dilution of precision.
+    pub hdop: f64,
+    /// Altitude above mean sea level in metres.
+    pub altitude: f64,
+    /// UTC time of the fix in seconds since midnight.
+    pub time: u32,
+    /// Number of satellites used in the fix.
+    pub satellites: u8,
+    /// GGA fix quality indicator.
+    pub quality: u8,
+}
+
+impl JsFix {
+    /// Combine the signed 10^-7 degree position of [`extract_gga_e7`] with the rest of the extended fix.
+    #[inline]
+    fn from_parts(e7_block: &[u8; 9], fix: &ExtendedFix) -> JsFix {
+        let lat = i32::from_be_bytes([e7_block[0], e7_block[1], e7_block[2], e7_block[3]]);
+        let lon = i32::from_be_bytes([e7_block[4], e7_block[5], e7_block[6], e7_block[7]]);
+        JsFix {
+            lat: lat as f64 / 10_000_000.0,
+            lon: lon as f64 / 10_000_000.0,
+            hdop: fix.position.hdop as f64 / 10.0,
+            altitude: fix.altitude as f64 / 10.0,
+            time: fix.time,
+            satellites: fix.satellites,
+            quality: fix.quality,
+        }
+    }
+}
+
+/// Determine if the sentence at `sentenceBegin` in a circular buffer is a GGA sentence.
+#[wasm_bindgen(js_name = isGga)]
+pub fn js_is_gga(buffer: &[u8], sentence_begin: usize) -> bool {
+    !buffer.is_empty() && is_gga(buffer, sentence_begin)
+}
+
+/// Extract the fix from a GGA sentence in a circular buffer, or `undefined` without a fix.
+#[wasm_bindgen(js_name = extractGga)]
+pub fn js_extract_gga(buffer: &[u8], sentence_begin: usize) -> Option<JsFix> {
+    if buffer.is_empty() {
+        return None;
+    }
+    let mut e7_block = [0; 9];
+    let mut extended_block = [0; 16];
+    if !extract_gga_e7(buffer, sentence_begin, &mut e7_block)
+        || !extract_gga_extended(buffer, sentence_begin, &mut extended_block)
+    {
+        return None;
+    }
+    decode_extended_block(&extended_block).map(|fix| JsFix::from_parts(&e7_block, &fix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_HIGH_LATITUDE, GGA_NO_FIX, GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_js_extract_gga() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            assert!(js_is_gga(&buffer, i));
+            let fix = js_extract_gga(&buffer, i).unwrap();
+            assert!((fix.lat - -27.5703288).abs() < 1e-9);
+            assert!((fix.lon - 153.0514878).abs() < 1e-9);
+            assert_eq!(fix.hdop, 2.8);
+            assert_eq!(fix.altitude, 103.4);
+            assert_eq!(fix.time, 18720);
+            assert_eq!(fix.satellites, 7);
+            assert_eq!(fix.quality, 1);
+
+            shift_buffer(&mut buffer, GGA_HIGH_LATITUDE, i);
+            let fix = js_extract_gga(&buffer, i).unwrap();
+            assert!((fix.lat - 51.5020575).abs() < 1e-9);
+            assert!((fix.lon - -0.127572).abs() < 1e-9);
+
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert_eq!(js_extract_gga(&buffer, i), None);
+        }
+        assert!(!js_is_gga(&[], 0));
+        assert_eq!(js_extract_gga(&[], 0), None);
+    }
+}
@@ -0,0 +1,95 @@
+//! Postcard encoding of extracted fixes.
+use crate::buffer::NmeaBuffer;
+use crate::decode::{DecodedFix, decode_position_block};
+use crate::degrees::total_minutes_at;
+use crate::extract_gga;
+
+/// Largest postcard encoding of a [`DecodedFix`]: two varint `u32`, two `bool` and a `u8`.
+pub const POSTCARD_FIX_MAX_LENGTH: usize = 13;
+
+/// Serialize a fix with postcard.
+///
+/// ### Returns
+/// Number of bytes written to `frame`.
+#[inline]
+pub fn encode_postcard(fix: &DecodedFix, frame: &mut [u8; POSTCARD_FIX_MAX_LENGTH]) -> usize {
+    // The frame is large enough for any fix, so serialization cannot fail
+    postcard::to_slice(fix, frame).map_or(0, |used| used.len())
+}
+
+/// Extract position data from a GGA sentence as a postcard-serialized [`DecodedFix`].
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the GGA sentence in the buffer.
+/// * `frame` - Output buffer where the serialized fix will be stored.
+///
+/// ### Returns
+/// Number of bytes written to `frame`, or `None` if the sentence does not contain a GNSS fix.
+#[inline]
+pub fn extract_gga_postcard<B: NmeaBuffer + ?Sized>(
+    buffer: &B,
+    sentence_begin: usize,
+    frame: &mut [u8; POSTCARD_FIX_MAX_LENGTH],
+) -> Option<usize> {
+    let mut position_block = [0; 10];
+    if !extract_gga(buffer, sentence_begin, &mut position_block) {
+        return None;
+    }
+    // The block latitude wraps from about 42.95°, so only take the hemispheres and HDOP from it
+    let (lat_minutes, lon_minutes) = total_minutes_at(buffer, sentence_begin);
+    let fix = DecodedFix {
+        lat_minutes,
+        lon_minutes,
+        ..decode_position_block(&position_block)
+    };
+    Some(encode_postcard(&fix, frame))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{GGA_HIGH_LATITUDE, GGA_NO_FIX, GGA_WITH_FIX, shift_buffer};
+
+    #[test]
+    fn test_extract_gga_postcard() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let mut frame = [0; POSTCARD_FIX_MAX_LENGTH];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, GGA_WITH_FIX, i);
+            let length = extract_gga_postcard(&buffer, i, &mut frame).unwrap();
+            let fix: DecodedFix = postcard::from_bytes(&frame[..length]).unwrap();
+            assert_eq!(fix.lat_minutes, 165421973);
+            assert_eq!(fix.lon_minutes, 918308927);
+            assert!(!fix.north);
+            assert!(fix.east);
+            assert_eq!(fix.hdop, 28);
+
+            shift_buffer(&mut buffer, GGA_HIGH_LATITUDE, i);
+            let length = extract_gga_postcard(&buffer, i, &mut frame).unwrap();
+            let fix: DecodedFix = postcard::from_bytes(&frame[..length]).unwrap();
+            assert_eq!(fix.lat_minutes, 309012345);
+            assert_eq!(fix.lon_minutes, 765432);
+            assert!(fix.north);
+            assert!(!fix.east);
+            assert_eq!(fix.hdop, 9);
+
+            shift_buffer(&mut buffer, GGA_NO_FIX, i);
+            assert_eq!(extract_gga_postcard(&buffer, i, &mut frame), None);
+        }
+    }
+
+    #[test]
+    fn test_encode_postcard_max_length() {
+        let fix = DecodedFix {
+            lat_minutes: u32::MAX,
+            lon_minutes: u32::MAX,
+            north: true,
+            east: true,
+            hdop: u8::MAX,
+        };
+        let mut frame = [0; POSTCARD_FIX_MAX_LENGTH];
+        assert_eq!(encode_postcard(&fix, &mut frame), POSTCARD_FIX_MAX_LENGTH);
+        assert_eq!(postcard::from_bytes::<DecodedFix>(&frame), Ok(fix));
+    }
+}
@@ -0,0 +1,103 @@
+//! Detect and parse XTE (Cross-Track Error, Measured) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a XTE sentence.
+#[inline]
+pub fn is_xte<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'X'
+        && byte_at(buffer, sentence_begin + 4) == b'T'
+        && byte_at(buffer, sentence_begin + 5) == b'E'
+}
+
+/// Extract the cross-track error from a XTE sentence in a circular buffer.
+///
+/// The cross-track block layout is:
+/// * `[0..2]` - Cross-track error magnitude in hundredths of a nautical mile, big-endian.
+/// * `[2]` - Flags: bit 1 is set if the sentence is valid, bit 0 is set if the direction to steer is right.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the XTE sentence in the buffer.
+/// * `cross_track_block` - Output buffer where parsed data will be stored (3 bytes).
+///
+/// ### Returns
+/// If the sentence contains a cross-track error, both status fields are valid and, from NMEA 2.3, the mode
+/// indicator is not `N`.
+#[inline]
+pub fn extract_xte<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, cross_track_block: &mut [u8; 3]) -> bool {
+    // Check magnitude field
+    if byte_at(buffer, sentence_begin + 11) == b',' {
+        return false;
+    }
+
+    let (magnitude, end) = parse_decimal(buffer, sentence_begin + 11, 2);
+    let magnitude = if magnitude < 65536 { magnitude as u16 } else { 65535 };
+    cross_track_block[0] = (magnitude >> 8) as u8;
+    cross_track_block[1] = magnitude as u8;
+
+    let direction = skip_field(buffer, end);
+
+    // Skip units, the mode indicator is only present from NMEA 2.3
+    let mode = skip_field(buffer, skip_field(buffer, direction));
+    let valid = byte_at(buffer, sentence_begin + 7) == b'A'
+        && byte_at(buffer, sentence_begin + 9) == b'A'
+        && byte_at(buffer, mode) != b'N';
+    cross_track_block[2] = ((valid as u8) << 1) | (byte_at(buffer, direction) == b'R') as u8;
+
+    valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_xte() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, XTE[0].0, i);
+            assert!(!is_xte(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_xte(&buffer, i));
+            assert!(!is_xte(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_xte_empty() {
+        let mut cross_track_block = [0; 3];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, XTE_EMPTY, i);
+            let parsed = extract_xte(&buffer, i, &mut cross_track_block);
+            assert!(!parsed);
+            assert_eq!(cross_track_block, [0; 3]);
+        }
+    }
+
+    #[test]
+    fn test_xte() {
+        let mut cross_track_block = [0; 3];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_cross_track_block, expected_valid) in XTE.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_xte(&buffer, i, &mut cross_track_block);
+                assert_eq!(parsed, *expected_valid);
+                assert_eq!(cross_track_block, *expected_cross_track_block);
+            }
+        }
+    }
+
+    const XTE_EMPTY: &[u8] = b"$GPXTE,V,V,,,N,N*5E\r\n";
+
+    const XTE: [(&[u8], [u8; 3], bool); 4] = [
+        // NMEA 2.3 with mode indicator
+        (b"$GPXTE,A,A,0.67,L,N,A*02\r\n", [0, 67, 2], true),
+        // NMEA 2.2 and earlier, no mode indicator
+        (b"$GPXTE,A,A,12.5,R,N*76\r\n", [4, 226, 3], true),
+        (b"$GPXTE,A,A,0.10,R,N,N*13\r\n", [0, 10, 1], false),
+        (b"$GPXTE,V,A,1.00,L,N*78\r\n", [0, 100, 0], false),
+    ];
+}
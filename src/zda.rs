@@ -0,0 +1,117 @@
+//! Detect and parse ZDA (Time and Date) sentences.
+use crate::buffer::NmeaBuffer;
+use crate::parse::{byte_at, digit_at, parse_decimal, skip_field};
+
+/// Determine if the sentence is a ZDA sentence.
+#[inline]
+pub fn is_zda<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize) -> bool {
+    byte_at(buffer, sentence_begin + 3) == b'Z'
+        && byte_at(buffer, sentence_begin + 4) == b'D'
+        && byte_at(buffer, sentence_begin + 5) == b'A'
+}
+
+/// Extract the UTC date and time from a ZDA sentence in a circular buffer.
+///
+/// The timestamp block layout is:
+/// * `[0]` - Hour.
+/// * `[1]` - Minute.
+/// * `[2]` - Second.
+/// * `[3..5]` - Millisecond, big-endian.
+/// * `[5]` - Day.
+/// * `[6]` - Month.
+/// * `[7..9]` - Year, big-endian.
+///
+/// ### Arguments
+/// * `buffer` - A circular buffer containing NMEA 0183 data.
+/// * `sentence_begin` - Starting index of the ZDA sentence in the buffer.
+/// * `timestamp_block` - Output buffer where the parsed timestamp will be stored (9 bytes).
+///
+/// ### Returns
+/// If the sentence contains both a time and a date.
+#[inline]
+pub fn extract_zda<B: NmeaBuffer + ?Sized>(buffer: &B, sentence_begin: usize, timestamp_block: &mut [u8; 9]) -> bool {
+    // Check time field
+    if byte_at(buffer, sentence_begin + 7) == b',' {
+        return false;
+    }
+
+    // Seconds may have 2 or 3 decimals
+    let (millis, end) = parse_decimal(buffer, sentence_begin + 11, 3);
+
+    // Check day field
+    let day_begin = skip_field(buffer, end);
+    if byte_at(buffer, day_begin) == b',' {
+        return false;
+    }
+    let (day, end) = parse_decimal(buffer, day_begin, 0);
+    let (month, end) = parse_decimal(buffer, skip_field(buffer, end), 0);
+    let (year, _) = parse_decimal(buffer, skip_field(buffer, end), 0);
+
+    timestamp_block[0] = (digit_at(buffer, sentence_begin + 7) * 10 + digit_at(buffer, sentence_begin + 8)) as u8;
+    timestamp_block[1] = (digit_at(buffer, sentence_begin + 9) * 10 + digit_at(buffer, sentence_begin + 10)) as u8;
+    timestamp_block[2] = (millis / 1000) as u8;
+    timestamp_block[3] = ((millis % 1000) >> 8) as u8;
+    timestamp_block[4] = (millis % 1000) as u8;
+    timestamp_block[5] = day as u8;
+    timestamp_block[6] = month as u8;
+    timestamp_block[7] = (year >> 8) as u8;
+    timestamp_block[8] = year as u8;
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::shift_buffer;
+
+    #[test]
+    fn test_is_zda() {
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, ZDA_VALID[0].0, i);
+            assert!(!is_zda(&buffer, (i.wrapping_sub(1)) & 1023));
+            assert!(is_zda(&buffer, i));
+            assert!(!is_zda(&buffer, (i + 1) & 1023));
+        }
+    }
+
+    #[test]
+    fn test_zda_empty() {
+        let mut timestamp_block = [0; 9];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for i in 0..1024 {
+            shift_buffer(&mut buffer, ZDA_EMPTY, i);
+            let parsed = extract_zda(&buffer, i, &mut timestamp_block);
+            assert!(!parsed);
+            assert_eq!(timestamp_block, [0; 9]);
+        }
+    }
+
+    #[test]
+    fn test_zda_valid() {
+        let mut timestamp_block = [0; 9];
+        let mut buffer: [u8; 1024] = [0; 1024];
+        for (sentence, expected_timestamp_block) in ZDA_VALID.iter() {
+            for i in 0..1024 {
+                shift_buffer(&mut buffer, sentence, i);
+                let parsed = extract_zda(&buffer, i, &mut timestamp_block);
+                assert!(parsed);
+                assert_eq!(timestamp_block, *expected_timestamp_block);
+            }
+        }
+    }
+
+    const ZDA_EMPTY: &[u8] = b"$GNZDA,,,,,,*56\r\n";
+
+    const ZDA_VALID: [(&[u8], [u8; 9]); 2] = [
+        (
+            b"$GNZDA,051200.993,15,06,2025,00,00*4A\r\n",
+            [5, 12, 0, 3, 225, 15, 6, 7, 233],
+        ),
+        (
+            b"$GPZDA,235959.50,31,12,2024,,*67\r\n",
+            [23, 59, 59, 1, 244, 31, 12, 7, 232],
+        ),
+    ];
+}
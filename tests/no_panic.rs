@@ -0,0 +1,53 @@
+//! Link-time check that the GGA parsing path cannot panic.
+//!
+//! With the `no-panic` feature, optimised builds of the functions used below fail to link if any panic path survives
+//! optimisation. Framing, scanning and the other sentence types are not covered by the attribute. Run with
+//! `cargo test --release --features no-panic`, which also links the unit tests. Overflow checks are off there,
+//! so this also runs in every debug build, where an arithmetic overflow on arbitrary input fails the test instead.
+//!
+//! This doubles as the codegen test of the checked variants: a bounds check left in the validating field parse is a
+//! panicking branch, so it fails the link the same way.
+
+use gga_extract::{extract_gga, extract_gga_branchless, extract_gga_slice, is_gga, try_extract_gga, verify_checksum};
+
+/// Run every checked function over the sentence at every offset of a circular buffer.
+fn parse_everywhere(sentence: &[u8]) {
+    let mut buffer = [0; 1024];
+    let mut position_block = [0; 10];
+    for i in 0..buffer.len() {
+        for (offset, &b) in sentence.iter().enumerate() {
+            buffer[(i + offset) % 1024] = b;
+        }
+        is_gga(&buffer, i);
+        extract_gga(&buffer, i, &mut position_block);
+        extract_gga_branchless(&buffer, i, &mut position_block);
+        let _ = try_extract_gga(&buffer, i);
+        verify_checksum(&buffer, i, sentence.len());
+
+        is_gga(&buffer[..], i);
+        extract_gga(&buffer[..], i, &mut position_block);
+        let _ = try_extract_gga(&buffer[..], i);
+        verify_checksum(&buffer[..], i, sentence.len());
+    }
+    extract_gga_slice(sentence);
+}
+
+#[test]
+fn test_no_panic() {
+    for sentence in SENTENCES {
+        parse_everywhere(sentence);
+    }
+    // Empty slices read as zeros
+    assert!(!is_gga(&[][..], 0));
+    extract_gga(&[][..], 0, &mut [0; 10]);
+}
+
+const SENTENCES: [&[u8]; 6] = [
+    b"$GNGGA,051200.993,2734.21973,S,15303.08927,E,1,07,2.8,103.4,M,41.1,M,,*59\r\n",
+    b"$GNGGA,181501.000,8959.99999,N,17959.99999,W,1,03,99.9,84.6,M,41.1,M,,*6E\r\n",
+    // Every digit at its maximum, beyond any valid position
+    b"$GNGGA,999999.999,9999.99999,N,99999.99999,E,9,99,99.9,99999.9,M,99.9,M,,*6E\r\n",
+    b"$GNGGA,051154.000,,,,,0,00,25.5,,,,,,*7E\r\n",
+    b"$GNGGA,\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+    b"$GNGGA,",
+];